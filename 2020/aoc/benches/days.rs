@@ -0,0 +1,101 @@
+//! Measures parsing and both parts of every day, on the example from the
+//! puzzle text and on the real input when it is available.
+//!
+//! Criterion can record a run and compare a later one against it:
+//!
+//! ```text
+//! cargo bench -p aoc --bench days -- --save-baseline before
+//! # ...change something...
+//! cargo bench -p aoc --bench days -- --baseline before
+//! ```
+//!
+//! Passing a filter such as `day07/` after the `--` runs a single day.
+
+use aoc2020_common::input::Source;
+use aoc2020_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::path::Path;
+
+/// The inputs to measure `day` on: its example fixture, and the real input
+/// found the same way the runner finds it.
+fn inputs(day: u8) -> Vec<(&'static str, String)> {
+    let example = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc crate lives inside the workspace")
+        .join(format!("day{}", day))
+        .join("fixtures")
+        .join("example.txt");
+    let example = Source::Path(example);
+    let real = Source::resolve(day, None);
+    [("example", example), ("real", real)]
+        .into_iter()
+        .filter_map(|(name, source)| Some((name, source.read().ok()?)))
+        .collect()
+}
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    for (name, input) in inputs(S::DAY) {
+        let parsed = match S::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(_) => continue,
+        };
+        group.bench_with_input(BenchmarkId::new("parse", name), input.as_str(), |b, input| {
+            b.iter(|| S::parse(black_box(input)))
+        });
+        // parts that fail on this input, like day 9 on its example, are
+        // measured on the other one only
+        if S::part1(&parsed).is_ok() {
+            group.bench_with_input(BenchmarkId::new("part1", name), &parsed, |b, parsed| {
+                b.iter(|| S::part1(black_box(parsed)))
+            });
+        }
+        if S::part2(&parsed).is_ok() {
+            group.bench_with_input(BenchmarkId::new("part2", name), &parsed, |b, parsed| {
+                b.iter(|| S::part2(black_box(parsed)))
+            });
+        }
+    }
+    group.finish();
+}
+
+/// Ranks every slope down a map much taller than the real one, where
+/// slopes with a large `dy` only reach a few of its rows.
+fn bench_rank_slopes(c: &mut Criterion) {
+    let (width, height) = (31, 10_000);
+    // a fixed pseudo-random forest, about one tile in four a tree
+    let mut state = 0x2545_f491_u64;
+    let mut input = String::new();
+    for _ in 0..height {
+        for _ in 0..width {
+            state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            input.push(if state >> 62 == 0 { '#' } else { '.' });
+        }
+        input.push('\n');
+    }
+    let map = day3::RoadMap::parse(&input).unwrap();
+    let mut group = c.benchmark_group("day03");
+    group.sample_size(10);
+    group.bench_function(BenchmarkId::new("rank_slopes", "tall"), |b| {
+        b.iter(|| black_box(&map).rank_slopes(1..=width, 1..=height))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    days,
+    bench_day::<day1::Day1>,
+    bench_day::<day2::Day2>,
+    bench_day::<day3::Day3>,
+    bench_rank_slopes,
+    bench_day::<day4::Day4>,
+    bench_day::<day5::Day5>,
+    bench_day::<day6::Day6>,
+    bench_day::<day7::Day7>,
+    bench_day::<day8::Day8>,
+    bench_day::<day9::Day9>,
+    bench_day::<day10::Day10>,
+    bench_day::<day11::Day11>,
+    bench_day::<day12::Day12>,
+);
+criterion_main!(days);
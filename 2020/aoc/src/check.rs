@@ -0,0 +1,127 @@
+//! Compares every day's answers against the ones recorded in `answers.toml`.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// The recorded answers for one input of one day.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub day: u8,
+    /// Relative to the directory of the answers file.
+    pub input: PathBuf,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnswersFile {
+    #[serde(default)]
+    answer: Vec<Expected>,
+}
+
+/// The answers file checked into the workspace.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc crate lives inside the workspace")
+        .join("answers.toml")
+}
+
+/// Reads an answers file, resolving its inputs relative to its directory.
+pub fn load(path: &Path) -> Result<Vec<Expected>> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("could not read answers from {}", path.display()))?;
+    let file: AnswersFile =
+        toml::from_str(&text).with_context(|| format!("could not parse {}", path.display()))?;
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    Ok(file
+        .answer
+        .into_iter()
+        .map(|e| Expected {
+            input: dir.join(&e.input),
+            ..e
+        })
+        .collect())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// Every recorded part matched.
+    Passed,
+    /// The input could not be read, so nothing was checked.
+    Skipped(String),
+    /// At least one part did not match or could not be solved.
+    Failed(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub day: u8,
+    pub input: PathBuf,
+    pub outcome: Outcome,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {} ({}): ", self.day, self.input.display())?;
+        match &self.outcome {
+            Outcome::Passed => write!(f, "ok"),
+            Outcome::Skipped(reason) => write!(f, "skipped, {}", reason),
+            Outcome::Failed(problems) => write!(f, "FAILED, {}", problems.join("; ")),
+        }
+    }
+}
+
+/// Solves one recorded input and compares the answers.
+pub fn check(expected: &Expected) -> Report {
+    let outcome = match std::fs::read_to_string(&expected.input) {
+        Err(e) => Outcome::Skipped(format!("input not readable: {}", e)),
+        Ok(input) => compare(expected, &input),
+    };
+    Report {
+        day: expected.day,
+        input: expected.input.clone(),
+        outcome,
+    }
+}
+
+fn compare(expected: &Expected, input: &str) -> Outcome {
+    let day = match crate::day(expected.day) {
+        Some(day) => day,
+        None => return Outcome::Failed(vec![format!("day {} is not solved", expected.day)]),
+    };
+    let answers = match (day.solve)(input) {
+        Ok(answers) => answers,
+        Err(e) => return Outcome::Failed(vec![format!("parsing failed: {:#}", e)]),
+    };
+
+    let mut problems = Vec::new();
+    let parts = [&expected.part1, &expected.part2];
+    for (part, (expected, actual)) in (1..).zip(parts.into_iter().zip(answers)) {
+        let expected = match expected {
+            Some(expected) => expected,
+            None => continue,
+        };
+        match actual {
+            Ok(actual) if actual.to_string() == *expected => {}
+            Ok(actual) => problems.push(format!(
+                "part {}: expected {}, got {}",
+                part, expected, actual
+            )),
+            Err(e) => problems.push(format!("part {} failed: {:#}", part, e)),
+        }
+    }
+    if problems.is_empty() {
+        Outcome::Passed
+    } else {
+        Outcome::Failed(problems)
+    }
+}
+
+/// Checks every recorded input in order.
+pub fn check_all(expected: &[Expected]) -> Vec<Report> {
+    expected.iter().map(check).collect()
+}
@@ -0,0 +1,47 @@
+//! Runs and checks every solved day through a common interface.
+
+use aoc2020_common::{run_with, solve, Args, Solution, Solved};
+use anyhow::Result;
+
+pub mod check;
+
+/// The only year solved in this workspace so far.
+pub const YEAR: u16 = 2020;
+
+/// The entry points of one day, with its solution type erased.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub run: fn(Args) -> Result<()>,
+    pub solve: fn(&str) -> Result<Solved>,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            run: run_with::<S>,
+            solve: solve::<S>,
+        }
+    }
+}
+
+/// Every solved day of `YEAR`, in order.
+pub const DAYS: [Day; 12] = [
+    Day::of::<day1::Day1>(),
+    Day::of::<day2::Day2>(),
+    Day::of::<day3::Day3>(),
+    Day::of::<day4::Day4>(),
+    Day::of::<day5::Day5>(),
+    Day::of::<day6::Day6>(),
+    Day::of::<day7::Day7>(),
+    Day::of::<day8::Day8>(),
+    Day::of::<day9::Day9>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+];
+
+pub fn day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
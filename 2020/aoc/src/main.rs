@@ -0,0 +1,126 @@
+use anyhow::{bail, Result};
+use aoc::check::{self, Outcome};
+use aoc::{DAYS, YEAR};
+use aoc2020_common::Args;
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+/// Runs the Advent of Code solutions.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Cmd,
+}
+
+#[derive(Subcommand)]
+enum Cmd {
+    /// Runs one day, or every day with --all
+    Run(RunArgs),
+
+    /// Compares every day's answers against the recorded ones
+    Check {
+        /// The answers file, `answers.toml` in the workspace by default
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+}
+
+#[derive(clap::Args)]
+struct RunArgs {
+    #[arg(long, default_value_t = YEAR)]
+    year: u16,
+
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Only runs the given part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Reads the input from this file, or from stdin when it is `-`
+    #[arg(long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+
+    /// Reads inputs from `day<N>.txt` files in this directory
+    #[arg(long)]
+    input_dir: Option<PathBuf>,
+
+    /// Runs every day
+    #[arg(long)]
+    all: bool,
+}
+
+fn run_day(day: u8, args: &RunArgs) -> Result<()> {
+    let input = match (&args.input, &args.input_dir) {
+        (Some(input), _) => Some(input.clone()),
+        (None, Some(dir)) => Some(dir.join(format!("day{}.txt", day))),
+        (None, None) => None,
+    };
+    let args = Args {
+        part: args.part,
+        input: input.map(Into::into),
+    };
+    match aoc::day(day) {
+        Some(day) => (day.run)(args),
+        None => bail!("day {} of {} is not solved", day, YEAR),
+    }
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    if args.year != YEAR {
+        bail!("only {} is solved, not {}", YEAR, args.year);
+    }
+    let days: Vec<u8> = match args.day {
+        Some(day) if aoc::day(day).is_none() => {
+            bail!("day {} of {} is not solved", day, YEAR)
+        }
+        Some(day) => vec![day],
+        None => DAYS.iter().map(|d| d.number).collect(),
+    };
+
+    let mut failed = 0;
+    for &day in &days {
+        if let Err(e) = run_day(day, &args) {
+            eprintln!("{:#}", e);
+            failed += 1;
+        }
+    }
+    if failed > 0 {
+        bail!("{} of {} day(s) failed", failed, days.len());
+    }
+    Ok(())
+}
+
+fn check(answers: Option<PathBuf>) -> Result<()> {
+    let answers = answers.unwrap_or_else(check::default_path);
+    let reports = check::check_all(&check::load(&answers)?);
+    for report in &reports {
+        println!("{}", report);
+    }
+    let failed = reports
+        .iter()
+        .filter(|r| matches!(r.outcome, Outcome::Failed(_)))
+        .count();
+    let skipped = reports
+        .iter()
+        .filter(|r| matches!(r.outcome, Outcome::Skipped(_)))
+        .count();
+    println!(
+        "{} passed, {} failed, {} skipped",
+        reports.len() - failed - skipped,
+        failed,
+        skipped
+    );
+    if failed > 0 {
+        bail!("{} input(s) did not match their recorded answers", failed);
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Cmd::Run(args) => run(args),
+        Cmd::Check { answers } => check(answers),
+    }
+}
@@ -0,0 +1,42 @@
+use aoc::check::{self, Expected, Outcome};
+
+#[test]
+fn recorded_answers_match() {
+    let reports = check::check_all(&check::load(&check::default_path()).unwrap());
+    let failures: Vec<String> = reports
+        .iter()
+        .filter(|r| matches!(r.outcome, Outcome::Failed(_)))
+        .map(ToString::to_string)
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn missing_input_is_skipped() {
+    let report = check::check(&Expected {
+        day: 1,
+        input: "does/not/exist.txt".into(),
+        part1: Some("1".into()),
+        part2: None,
+    });
+    assert!(matches!(report.outcome, Outcome::Skipped(_)), "{}", report);
+}
+
+#[test]
+fn wrong_answer_is_reported() {
+    let input = std::env::temp_dir().join(format!("aoc-check-{}.txt", std::process::id()));
+    std::fs::write(&input, "1010\n1000\n1010\n10\n").unwrap();
+    let report = check::check(&Expected {
+        day: 1,
+        input: input.clone(),
+        part1: Some("1".into()),
+        part2: Some("10100000".into()),
+    });
+    std::fs::remove_file(input).unwrap();
+    match report.outcome {
+        Outcome::Failed(problems) => {
+            assert_eq!(problems, vec!["part 1: expected 1, got 1020100".to_string()])
+        }
+        outcome => panic!("unexpected outcome {:?}", outcome),
+    }
+}
@@ -0,0 +1,195 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A signed integer usable as a `Vec2` coordinate.
+pub trait Coord:
+    Copy
+    + Debug
+    + Default
+    + Eq
+    + Ord
+    + Hash
+    + From<i8>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_coord!(i8, i16, i32, i64, i128, isize);
+
+/// A position or displacement on a 2D grid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// Converts both coordinates, failing if either does not fit in `U`.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Result<Vec2<U>, U::Error> {
+        Ok(Vec2 {
+            x: self.x.try_into()?,
+            y: self.y.try_into()?,
+        })
+    }
+
+    /// Converts both coordinates losslessly.
+    pub fn cast<U: From<T>>(self) -> Vec2<U> {
+        Vec2 {
+            x: self.x.into(),
+            y: self.y.into(),
+        }
+    }
+}
+
+impl<T: Coord> Vec2<T> {
+    /// The four orthogonal unit steps: east, south, west and north, with +y
+    /// pointing north.
+    pub fn offsets4() -> [Self; 4] {
+        let (zero, one) = (T::from(0), T::from(1));
+        [
+            Self::new(one, zero),
+            Self::new(zero, -one),
+            Self::new(-one, zero),
+            Self::new(zero, one),
+        ]
+    }
+
+    /// The four orthogonal and four diagonal unit steps.
+    pub fn offsets8() -> [Self; 8] {
+        let (zero, one) = (T::from(0), T::from(1));
+        [
+            Self::new(-one, -one),
+            Self::new(zero, -one),
+            Self::new(one, -one),
+            Self::new(-one, zero),
+            Self::new(one, zero),
+            Self::new(-one, one),
+            Self::new(zero, one),
+            Self::new(one, one),
+        ]
+    }
+
+    /// The positions sharing an edge with this one.
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Self::offsets4().into_iter().map(move |d| self + d)
+    }
+
+    /// The positions sharing an edge or a corner with this one.
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Self::offsets8().into_iter().map(move |d| self + d)
+    }
+
+    /// Rotates by `quarter_turns` of 90°. Positive turns take +x to -y, which
+    /// is clockwise when +y points up.
+    pub fn rotate(self, quarter_turns: isize) -> Self {
+        let Self { x, y } = self;
+        match quarter_turns.rem_euclid(4) {
+            0 => Self { x, y },
+            1 => Self { x: y, y: -x },
+            2 => Self { x: -x, y: -y },
+            3 => Self { x: -y, y: x },
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> T {
+        let d = self - other;
+        d.x.abs() + d.y.abs()
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> T {
+        let d = self - other;
+        d.x.abs().max(d.y.abs())
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Vec2<T>> for (T, T) {
+    fn from(v: Vec2<T>) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use crate::Vec2;
+use std::fmt::{self, Debug};
+
+/// What happens to positions that fall outside the grid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Boundary {
+    /// Positions outside the grid hold nothing.
+    #[default]
+    Bounded,
+    /// The grid repeats to the left and right, but not up and down.
+    WrapX,
+    /// The grid repeats up and down, but not to the left and right.
+    WrapY,
+    /// The grid repeats in every direction.
+    Torus,
+    /// The grid repeats in every direction, mirrored at each edge, so that
+    /// stepping off one side comes back in on the same side.
+    Mirror,
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    #[error("unexpected character {found:?} at row {row}, column {col}")]
+    UnexpectedChar { found: char, row: usize, col: usize },
+
+    #[error("row {row} has {found} columns, but the first row has {expected}")]
+    RaggedRow {
+        row: usize,
+        found: usize,
+        expected: usize,
+    },
+}
+
+/// Folds `v` into `0..len`, as if bouncing back and forth between the
+/// edges: `len` maps to `len - 1` and `-1` to `0`.
+fn reflect(v: i64, len: i64) -> i64 {
+    let v = v.rem_euclid(2 * len);
+    if v < len { v } else { 2 * len - 1 - v }
+}
+
+/// A rectangular grid of tiles, stored row by row with +y pointing down.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    size: Vec2<i64>,
+    tiles: Vec<T>,
+    boundary: Boundary,
+}
+
+impl<T: Default> Grid<T> {
+    /// A bounded grid where every tile is `T::default()`.
+    pub fn new(size: Vec2<i64>) -> Self {
+        let num_tiles = size.x.max(0) * size.y.max(0);
+        Self {
+            size,
+            tiles: (0..num_tiles).map(|_| Default::default()).collect(),
+            boundary: Default::default(),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses one row per line, mapping each character to a tile.
+    ///
+    /// Empty lines are skipped, so trailing newlines are fine.
+    pub fn parse(input: &str, mut tile: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        let mut tiles = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (row, line) in input.lines().filter(|l| !l.is_empty()).enumerate() {
+            let before = tiles.len();
+            for (col, c) in line.chars().enumerate() {
+                tiles.push(tile(c).ok_or(GridError::UnexpectedChar { found: c, row, col })?);
+            }
+            let found = tiles.len() - before;
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(GridError::RaggedRow {
+                        row,
+                        found,
+                        expected,
+                    })
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Self {
+            size: Vec2::new(width.unwrap_or_default() as _, height),
+            tiles,
+            boundary: Default::default(),
+        })
+    }
+
+    pub fn with_boundary(self, boundary: Boundary) -> Self {
+        Self { boundary, ..self }
+    }
+
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    pub fn size(&self) -> Vec2<i64> {
+        self.size
+    }
+
+    pub fn width(&self) -> i64 {
+        self.size.x
+    }
+
+    pub fn height(&self) -> i64 {
+        self.size.y
+    }
+
+    /// Maps `pos` into the grid according to the boundary, or returns `None`
+    /// if it lies outside.
+    pub fn normalize_pos(&self, pos: Vec2<i64>) -> Option<Vec2<i64>> {
+        let Vec2 { x: w, y: h } = self.size;
+        if w <= 0 || h <= 0 {
+            return None;
+        }
+        let x_inside = (0..w).contains(&pos.x);
+        let y_inside = (0..h).contains(&pos.y);
+        match self.boundary {
+            Boundary::Bounded if x_inside && y_inside => Some(pos),
+            Boundary::WrapX if y_inside => Some(Vec2::new(pos.x.rem_euclid(w), pos.y)),
+            Boundary::WrapY if x_inside => Some(Vec2::new(pos.x, pos.y.rem_euclid(h))),
+            Boundary::Torus => Some(Vec2::new(pos.x.rem_euclid(w), pos.y.rem_euclid(h))),
+            Boundary::Mirror => Some(Vec2::new(reflect(pos.x, w), reflect(pos.y, h))),
+            _ => None,
+        }
+    }
+
+    pub fn index(&self, pos: Vec2<i64>) -> Option<usize> {
+        self.normalize_pos(pos)
+            .map(|pos| (pos.x + pos.y * self.size.x) as _)
+    }
+
+    pub fn get(&self, pos: Vec2<i64>) -> Option<&T> {
+        self.index(pos).map(|i| &self.tiles[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Vec2<i64>) -> Option<&mut T> {
+        self.index(pos).map(move |i| &mut self.tiles[i])
+    }
+
+    /// Replaces the tile at `pos`, doing nothing if it lies outside.
+    pub fn set(&mut self, pos: Vec2<i64>, tile: T) {
+        if let Some(slot) = self.get_mut(pos) {
+            *slot = tile;
+        }
+    }
+
+    pub fn row(&self, y: i64) -> Option<&[T]> {
+        let width = self.size.x as usize;
+        self.index(Vec2::new(0, y))
+            .map(|start| &self.tiles[start..start + width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.tiles.chunks(self.size.x.max(1) as _)
+    }
+
+    pub fn column(&self, x: i64) -> impl Iterator<Item = &T> + '_ {
+        let start = self.index(Vec2::new(x, 0)).unwrap_or(self.tiles.len());
+        self.tiles[start..].iter().step_by(self.size.x.max(1) as _)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.size.x).map(move |x| self.column(x))
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Vec2<i64>> {
+        let Vec2 { x: w, y: h } = self.size;
+        (0..h).flat_map(move |y| (0..w).map(move |x| Vec2::new(x, y)))
+    }
+
+    /// Every tile with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2<i64>, &T)> + '_ {
+        self.positions().zip(self.tiles.iter())
+    }
+
+    /// The tiles sharing an edge with `pos`, with their normalized positions.
+    pub fn neighbors4(&self, pos: Vec2<i64>) -> impl Iterator<Item = (Vec2<i64>, &T)> + '_ {
+        self.lookup(pos.neighbors4())
+    }
+
+    /// The tiles sharing an edge or a corner with `pos`, with their
+    /// normalized positions.
+    pub fn neighbors8(&self, pos: Vec2<i64>) -> impl Iterator<Item = (Vec2<i64>, &T)> + '_ {
+        self.lookup(pos.neighbors8())
+    }
+
+    fn lookup(
+        &self,
+        positions: impl Iterator<Item = Vec2<i64>> + 'static,
+    ) -> impl Iterator<Item = (Vec2<i64>, &T)> + '_ {
+        positions.filter_map(move |pos| {
+            let pos = self.normalize_pos(pos)?;
+            Some((pos, self.get(pos)?))
+        })
+    }
+
+    /// Builds a grid of the same size and boundary from every tile and its
+    /// position.
+    pub fn map<U>(&self, mut f: impl FnMut(Vec2<i64>, &T) -> U) -> Grid<U> {
+        Grid {
+            size: self.size,
+            tiles: self.iter().map(|(pos, tile)| f(pos, tile)).collect(),
+            boundary: self.boundary,
+        }
+    }
+
+    /// Draws the grid with one character per tile and a newline after each
+    /// row.
+    pub fn render(&self, mut f: impl FnMut(Vec2<i64>, &T) -> char) -> String {
+        let mut res = String::with_capacity(self.tiles.len() + self.size.y.max(0) as usize);
+        for (pos, tile) in self.iter() {
+            res.push(f(pos, tile));
+            if pos.x == self.size.x - 1 {
+                res.push('\n');
+            }
+        }
+        res
+    }
+}
+
+impl<T: Debug> Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for tile in row {
+                write!(f, "{:?}", tile)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
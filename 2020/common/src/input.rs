@@ -0,0 +1,59 @@
+use anyhow::{Context, Result};
+use std::ffi::OsString;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming a directory that holds `day<N>.txt` inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a day reads its puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    Path(PathBuf),
+}
+
+impl Source {
+    /// Picks the source for `day` from a command-line argument, falling back
+    /// to `$AOC_INPUT_DIR/day<N>.txt` and then to `day<N>/src/input.txt` in
+    /// the workspace.
+    pub fn resolve(day: u8, arg: Option<OsString>) -> Self {
+        match arg {
+            Some(arg) if arg == "-" => Self::Stdin,
+            Some(arg) => Self::Path(arg.into()),
+            None => match std::env::var_os(INPUT_DIR_VAR) {
+                Some(dir) => Self::Path(Path::new(&dir).join(format!("day{}.txt", day))),
+                None => Self::Path(default_path(day)),
+            },
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Self::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .context("could not read puzzle input from stdin")?;
+                Ok(input)
+            }
+            Self::Path(path) => std::fs::read_to_string(path).with_context(|| {
+                format!(
+                    "could not read puzzle input from {}; pass a path, '-' for stdin, or set {}",
+                    path.display(),
+                    INPUT_DIR_VAR
+                )
+            }),
+        }
+    }
+}
+
+/// The input checked out next to the sources of `day`.
+pub fn default_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("common crate lives inside the workspace")
+        .join(format!("day{}", day))
+        .join("src")
+        .join("input.txt")
+}
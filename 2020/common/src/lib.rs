@@ -0,0 +1,15 @@
+//! Helpers shared by the Advent of Code 2020 solutions.
+
+pub mod geom;
+pub mod grid;
+pub mod input;
+mod solution;
+
+pub use geom::Vec2;
+pub use grid::{Boundary, Grid};
+pub use solution::{
+    run, run_with, solve, take_flag, take_option, Answer, Args, Solution, Solved,
+};
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,169 @@
+use crate::input::Source;
+use anyhow::{anyhow, bail, Result};
+use std::ffi::OsString;
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Self::Number(n as _)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(i16, i32, i64, isize, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Text(s.into())
+    }
+}
+
+/// A day's puzzle, split into parsing and the two parts.
+pub trait Solution {
+    const DAY: u8;
+
+    /// The parsed input, which may borrow from the input text.
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>>;
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Answer>;
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Answer>;
+
+    /// Problems with the input that the solution worked around, such as
+    /// lines it skipped.
+    fn warnings(_parsed: &Self::Parsed<'_>) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// The answers to both parts of a day.
+pub type Solved = [Result<Answer>; 2];
+
+/// Parses `input` and solves both parts of `S`.
+pub fn solve<S: Solution>(input: &str) -> Result<Solved> {
+    let parsed = S::parse(input)?;
+    Ok([S::part1(&parsed), S::part2(&parsed)])
+}
+
+/// Options accepted by every day's binary: `[--part <1|2>] [INPUT]`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Args {
+    pub part: Option<u8>,
+    pub input: Option<OsString>,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = OsString>) -> Result<Self> {
+        let mut res = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == "--part" {
+                let part = args.next().ok_or_else(|| anyhow!("--part needs a value"))?;
+                res.part = match part.to_str() {
+                    Some("1") => Some(1),
+                    Some("2") => Some(2),
+                    _ => bail!("--part must be 1 or 2, got {:?}", part),
+                };
+            } else if res.input.is_none() {
+                res.input = Some(arg);
+            } else {
+                bail!("unexpected argument {:?}", arg);
+            }
+        }
+        Ok(res)
+    }
+}
+
+/// Removes the option `name` and the value after it from `args`, for
+/// binaries that accept more than `Args` does.
+pub fn take_option(args: &mut Vec<OsString>, name: &str) -> Result<Option<OsString>> {
+    match args.iter().position(|a| a == name) {
+        Some(i) if i + 1 < args.len() => Ok(args.drain(i..i + 2).nth(1)),
+        Some(_) => bail!("{} needs a value", name),
+        None => Ok(None),
+    }
+}
+
+/// Removes the flag `name` from `args`, returning whether it was there.
+pub fn take_flag(args: &mut Vec<OsString>, name: &str) -> bool {
+    match args.iter().position(|a| a == name) {
+        Some(i) => {
+            args.remove(i);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Runs `S` as described by the command-line arguments and prints the
+/// answers.
+pub fn run<S: Solution>() -> Result<()> {
+    run_with::<S>(Args::parse(std::env::args_os().skip(1))?)
+}
+
+/// Runs `S` as described by `args` and prints the answers.
+///
+/// A part that fails does not stop the other one from running. Warnings are
+/// printed to stderr after the answers.
+pub fn run_with<S: Solution>(args: Args) -> Result<()> {
+    let input = Source::resolve(S::DAY, args.input).read()?;
+    let parsed = S::parse(&input)?;
+    let mut failed = 0;
+    for part in [1, 2] {
+        if args.part.is_some_and(|p| p != part) {
+            continue;
+        }
+        let answer = if part == 1 {
+            S::part1(&parsed)
+        } else {
+            S::part2(&parsed)
+        };
+        match answer {
+            Ok(answer) => println!("Day {} part {}: {}", S::DAY, part, answer),
+            Err(e) => {
+                eprintln!("Day {} part {} failed: {:#}", S::DAY, part, e);
+                failed += 1;
+            }
+        }
+    }
+    let warnings = S::warnings(&parsed);
+    if !warnings.is_empty() {
+        eprintln!("Day {}: {} warning(s)", S::DAY, warnings.len());
+        for warning in warnings {
+            eprintln!("  {}", warning);
+        }
+    }
+    if failed > 0 {
+        bail!("{} part(s) of day {} failed", failed, S::DAY);
+    }
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use super::*;
+
+#[test]
+fn test_input_source() {
+    use input::Source;
+    use std::ffi::OsString;
+
+    assert_eq!(Source::resolve(1, Some(OsString::from("-"))), Source::Stdin);
+    assert_eq!(
+        Source::resolve(1, Some(OsString::from("other.txt"))),
+        Source::Path("other.txt".into())
+    );
+    assert!(input::default_path(11).ends_with("day11/src/input.txt"));
+}
+
+#[test]
+fn test_missing_input() {
+    let err = input::Source::Path("does/not/exist.txt".into())
+        .read()
+        .unwrap_err();
+    assert!(err.to_string().contains("does/not/exist.txt"));
+}
+
+#[test]
+fn test_answer_display() {
+    assert_eq!(Answer::from(1322306994176usize).to_string(), "1322306994176");
+    assert_eq!(Answer::from(-3i64), Answer::Number(-3));
+    assert_eq!(Answer::from("abc").to_string(), "abc");
+}
+
+#[test]
+fn test_args() {
+    use std::ffi::OsString;
+
+    let parse = |args: &[&str]| Args::parse(args.iter().map(OsString::from));
+    assert_eq!(parse(&[]).unwrap(), Args::default());
+    assert_eq!(
+        parse(&["--part", "2", "input.txt"]).unwrap(),
+        Args {
+            part: Some(2),
+            input: Some("input.txt".into())
+        }
+    );
+    assert!(parse(&["--part", "3"]).is_err());
+    assert!(parse(&["a.txt", "b.txt"]).is_err());
+}
+
+#[test]
+fn test_take_option() {
+    use std::ffi::OsString;
+
+    let mut args: Vec<OsString> = ["--render", "ansi", "--part", "1", "--all", "in.txt"]
+        .iter()
+        .map(OsString::from)
+        .collect();
+    assert_eq!(take_option(&mut args, "--render").unwrap(), Some("ansi".into()));
+    assert_eq!(take_option(&mut args, "--render").unwrap(), None);
+    assert!(take_flag(&mut args, "--all"));
+    assert!(!take_flag(&mut args, "--all"));
+    assert_eq!(args, ["--part", "1", "in.txt"]);
+    assert!(take_option(&mut args, "in.txt").is_err(), "no value after it");
+}
+
+#[test]
+fn test_vec2_arithmetic() {
+    let a = Vec2::new(3i64, -2);
+    let b: Vec2<i64> = (1, 5).into();
+    assert_eq!(a + b, Vec2::new(4, 3));
+    assert_eq!(a - b, Vec2::new(2, -7));
+    assert_eq!(a * 3, Vec2::new(9, -6));
+    assert_eq!(-a, Vec2::new(-3, 2));
+    assert_eq!(<(i64, i64)>::from(a), (3, -2));
+}
+
+#[test]
+fn test_vec2_rotate() {
+    let v = Vec2::new(10isize, 4);
+    assert_eq!(v.rotate(1), Vec2::new(4, -10));
+    assert_eq!(v.rotate(2), Vec2::new(-10, -4));
+    assert_eq!(v.rotate(-1), Vec2::new(-4, 10));
+    assert_eq!(v.rotate(4), v);
+}
+
+#[test]
+fn test_vec2_neighbors() {
+    let v = Vec2::new(0i32, 0);
+    assert_eq!(v.neighbors4().count(), 4);
+    assert!(v.neighbors4().all(|n| n.manhattan_distance(v) == 1));
+    assert_eq!(v.neighbors8().count(), 8);
+    assert!(v.neighbors8().all(|n| n.chebyshev_distance(v) == 1));
+}
+
+#[test]
+fn test_vec2_distances() {
+    let a = Vec2::new(17i64, -8);
+    assert_eq!(a.manhattan_distance(Vec2::default()), 25);
+    assert_eq!(a.chebyshev_distance(Vec2::new(1, 1)), 16);
+}
+
+#[test]
+fn test_vec2_cast() {
+    let v = Vec2::new(3i32, -4);
+    assert_eq!(v.cast::<i64>(), Vec2::new(3i64, -4));
+    assert!(v.try_cast::<u8>().is_err());
+    assert_eq!(Vec2::new(3i64, 4).try_cast::<usize>(), Ok(Vec2::new(3, 4)));
+}
+
+fn parse_digits(input: &str) -> Grid<u32> {
+    Grid::parse(input, |c| c.to_digit(10)).unwrap()
+}
+
+#[test]
+fn test_grid_parse() {
+    let grid = parse_digits("123\n456\n");
+    assert_eq!(grid.size(), Vec2::new(3, 2));
+    assert_eq!(grid.get(Vec2::new(2, 1)), Some(&6));
+    assert_eq!(format!("{:?}", grid), "123\n456\n");
+
+    assert_eq!(
+        Grid::parse("12\n3x", |c| c.to_digit(10)),
+        Err(grid::GridError::UnexpectedChar {
+            found: 'x',
+            row: 1,
+            col: 1
+        })
+    );
+    assert_eq!(
+        Grid::parse("12\n345", |c| c.to_digit(10)),
+        Err(grid::GridError::RaggedRow {
+            row: 1,
+            found: 3,
+            expected: 2
+        })
+    );
+}
+
+#[test]
+fn test_grid_boundary() {
+    let grid = parse_digits("12\n34");
+    let pos = Vec2::new(-1, 2);
+    assert_eq!(grid.get(pos), None);
+    let grid = grid.with_boundary(Boundary::WrapX);
+    assert_eq!(grid.get(pos), None);
+    assert_eq!(grid.get(Vec2::new(-1, 1)), Some(&4));
+    let grid = grid.with_boundary(Boundary::Torus);
+    assert_eq!(grid.get(pos), Some(&2));
+    let grid = grid.with_boundary(Boundary::WrapY);
+    assert_eq!(grid.get(pos), None);
+    assert_eq!(grid.get(Vec2::new(1, -1)), Some(&4));
+    let grid = grid.with_boundary(Boundary::Mirror);
+    assert_eq!(grid.get(pos), Some(&3));
+    assert_eq!(grid.normalize_pos(Vec2::new(5, -3)), Some(Vec2::new(1, 1)));
+}
+
+#[test]
+fn test_grid_rows_and_columns() {
+    let grid = parse_digits("123\n456");
+    assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&[1, 2, 3], &[4, 5, 6]]);
+    assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+    let columns: Vec<Vec<u32>> = grid.columns().map(|c| c.copied().collect()).collect();
+    assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+}
+
+#[test]
+fn test_grid_neighbors() {
+    let grid = parse_digits("123\n456\n789");
+    let mut around: Vec<u32> = grid.neighbors8(Vec2::new(0, 0)).map(|(_, &t)| t).collect();
+    around.sort();
+    assert_eq!(around, vec![2, 4, 5]);
+    assert_eq!(grid.neighbors4(Vec2::new(1, 1)).map(|(_, &t)| t).sum::<u32>(), 20);
+
+    let grid = grid.with_boundary(Boundary::Torus);
+    assert_eq!(grid.neighbors8(Vec2::new(0, 0)).count(), 8);
+}
+
+#[test]
+fn test_grid_render() {
+    let grid = parse_digits("12\n34");
+    let doubled = grid.map(|_, &t| t * 2);
+    assert_eq!(doubled.get(Vec2::new(1, 1)), Some(&8));
+    let rendered = grid.render(|pos, &t| if pos.x == pos.y { '#' } else { char::from_digit(t, 10).unwrap() });
+    assert_eq!(rendered, "#2\n3#\n");
+}
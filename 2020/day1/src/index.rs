@@ -0,0 +1,99 @@
+//! Answering many pair and triple queries over the same list.
+
+use crate::{KSum, NoSolution};
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+use std::io::Write;
+
+/// A list of entries preprocessed so that each pair query is a lookup and
+/// each triple query takes O(n log n).
+pub struct SumIndex {
+    /// The entries with their position in the list, by value.
+    sorted: Vec<(i64, usize)>,
+    /// Every pair of positions `(i, j)` in `sorted` with `i < j`, by their
+    /// sum, in increasing order of `i`.
+    pairs: HashMap<i128, Vec<(usize, usize)>>,
+}
+
+impl SumIndex {
+    /// Builds the index in O(n²) time and space.
+    pub fn new(numbers: &[i64]) -> Self {
+        let mut sorted: Vec<(i64, usize)> = numbers.iter().copied().zip(0..).collect();
+        sorted.sort_unstable();
+        let mut pairs: HashMap<_, Vec<_>> = HashMap::new();
+        for i in 0..sorted.len() {
+            for j in i + 1..sorted.len() {
+                let sum = i128::from(sorted[i].0) + i128::from(sorted[j].0);
+                pairs.entry(sum).or_default().push((i, j));
+            }
+        }
+        Self { sorted, pairs }
+    }
+
+    /// Two entries summing to `target`.
+    pub fn pair(&self, target: i64) -> Result<KSum, NoSolution> {
+        self.pairs
+            .get(&target.into())
+            .and_then(|pairs| pairs.first())
+            .map(|&(i, j)| KSum::from_sorted(&self.sorted, [i, j]))
+            .ok_or(NoSolution { k: 2, target })
+    }
+
+    /// Three entries summing to `target`.
+    pub fn triple(&self, target: i64) -> Result<KSum, NoSolution> {
+        self.sorted
+            .iter()
+            .enumerate()
+            .find_map(|(i, &(value, _))| {
+                let pairs = self.pairs.get(&(i128::from(target) - i128::from(value)))?;
+                // the pair has to start after `i` so no entry is used twice
+                let &(j, k) = pairs.get(pairs.partition_point(|&(j, _)| j <= i))?;
+                Some(KSum::from_sorted(&self.sorted, [i, j, k]))
+            })
+            .ok_or(NoSolution { k: 3, target })
+    }
+
+    /// Answers one query per line of `queries`, each a tuple size of 2 or 3
+    /// and a target such as `3 2020`.
+    ///
+    /// Writes one line per query to `out`: the values of the entries in list
+    /// order, or `none`. Blank lines are skipped.
+    pub fn answer_queries(&self, queries: &str, out: &mut impl Write) -> Result<()> {
+        for (line_no, line) in (1..).zip(queries.lines()) {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (k, target) =
+                parse_query(line).with_context(|| format!("query on line {}", line_no))?;
+            let found = match k {
+                2 => self.pair(target),
+                _ => self.triple(target),
+            };
+            match found {
+                Ok(found) => {
+                    let values: Vec<_> = found.values.iter().map(i64::to_string).collect();
+                    writeln!(out, "{}", values.join(" "))?;
+                }
+                Err(_) => writeln!(out, "none")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_query(line: &str) -> Result<(usize, i64)> {
+    let mut fields = line.split_whitespace();
+    let (k, target) = match (fields.next(), fields.next(), fields.next()) {
+        (Some(k), Some(target), None) => (k, target),
+        _ => bail!("expected a tuple size and a target, got {:?}", line),
+    };
+    let k = match k {
+        "2" => 2,
+        "3" => 3,
+        _ => bail!("tuple size must be 2 or 3, got {:?}", k),
+    };
+    let target = target
+        .parse()
+        .with_context(|| format!("invalid target {:?}", target))?;
+    Ok((k, target))
+}
@@ -0,0 +1,187 @@
+//! Finding `k` entries of a list that add up to a target.
+
+/// `k` distinct entries of a list that sum to the target, ordered by their
+/// position in the list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KSum {
+    pub indices: Vec<usize>,
+    pub values: Vec<i64>,
+}
+
+/// No `k` entries of the list sum to `target`.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("no {k} entries of the input sum to {target}")]
+pub struct NoSolution {
+    pub k: usize,
+    pub target: i64,
+}
+
+impl KSum {
+    /// The entries at `positions` of a list of `(value, index)` pairs.
+    pub(crate) fn from_sorted(
+        sorted: &[(i64, usize)],
+        positions: impl IntoIterator<Item = usize>,
+    ) -> Self {
+        let mut picked: Vec<(usize, i64)> = positions
+            .into_iter()
+            .map(|p| (sorted[p].1, sorted[p].0))
+            .collect();
+        picked.sort_unstable();
+        Self {
+            indices: picked.iter().map(|&(i, _)| i).collect(),
+            values: picked.iter().map(|&(_, v)| v).collect(),
+        }
+    }
+
+    /// The product of the entries, or `None` if it does not fit in an
+    /// `i128`.
+    pub fn product(&self) -> Option<i128> {
+        self.values
+            .iter()
+            .try_fold(1i128, |acc, &v| acc.checked_mul(v.into()))
+    }
+}
+
+/// Finds `k` entries of `numbers`, each used at most once, that sum to
+/// `target`.
+///
+/// Sorts the entries once, then fixes all but the last two and closes in on
+/// those from both ends, which takes O(n^(k-1)) for `k >= 2`.
+pub fn find_k_sum(numbers: &[i64], k: usize, target: i64) -> Result<KSum, NoSolution> {
+    let mut sorted: Vec<(i64, usize)> = numbers.iter().copied().zip(0..).collect();
+    sorted.sort_unstable();
+
+    let mut chosen = Vec::with_capacity(k);
+    if !search(&sorted, k, target.into(), &mut chosen) {
+        return Err(NoSolution { k, target });
+    }
+    Ok(KSum::from_sorted(&sorted, chosen))
+}
+
+/// Every distinct way of picking `k` entries of `numbers` that sum to
+/// `target`, as ascending values in lexicographic order.
+///
+/// The entries form a multiset: a value listed twice can be picked twice,
+/// but picking either copy counts as the same combination.
+pub fn all_k_sums(numbers: &[i64], k: usize, target: i64) -> Result<Vec<Vec<i64>>, NoSolution> {
+    let mut res = Vec::new();
+    for_each_k_sum(numbers, k, target, |values| res.push(values.to_vec()));
+    if res.is_empty() {
+        return Err(NoSolution { k, target });
+    }
+    Ok(res)
+}
+
+/// How many combinations `all_k_sums` would return, without collecting them.
+pub fn count_k_sums(numbers: &[i64], k: usize, target: i64) -> usize {
+    let mut count = 0;
+    for_each_k_sum(numbers, k, target, |_| count += 1);
+    count
+}
+
+fn for_each_k_sum(numbers: &[i64], k: usize, target: i64, mut f: impl FnMut(&[i64])) {
+    let mut sorted = numbers.to_vec();
+    sorted.sort_unstable();
+    let mut prefix = Vec::with_capacity(k);
+    visit(&sorted, k, target.into(), &mut prefix, &mut f);
+}
+
+/// Calls `f` with `prefix` followed by every distinct combination of `k`
+/// values from `sorted` that sums to `target`.
+fn visit(
+    sorted: &[i64],
+    k: usize,
+    target: i128,
+    prefix: &mut Vec<i64>,
+    f: &mut impl FnMut(&[i64]),
+) {
+    let value = |i: usize| i128::from(sorted[i]);
+    match k {
+        0 => {
+            if target == 0 {
+                f(prefix);
+            }
+        }
+        1 => {
+            if let Ok(i) = sorted.binary_search_by(|&v| i128::from(v).cmp(&target)) {
+                prefix.push(sorted[i]);
+                f(prefix);
+                prefix.pop();
+            }
+        }
+        2 => {
+            let (mut lo, mut hi) = (0, sorted.len());
+            while lo + 1 < hi {
+                let sum = value(lo) + value(hi - 1);
+                if sum <= target {
+                    if sum == target {
+                        prefix.extend([sorted[lo], sorted[hi - 1]]);
+                        f(prefix);
+                        prefix.truncate(prefix.len() - 2);
+                    }
+                    // skip the other copies of this value
+                    let low = sorted[lo];
+                    while lo < hi && sorted[lo] == low {
+                        lo += 1;
+                    }
+                } else {
+                    hi -= 1;
+                }
+            }
+        }
+        _ => {
+            for i in 0..sorted.len() {
+                if i > 0 && sorted[i] == sorted[i - 1] {
+                    continue;
+                }
+                prefix.push(sorted[i]);
+                visit(&sorted[i + 1..], k - 1, target - value(i), prefix, f);
+                prefix.pop();
+            }
+        }
+    }
+}
+
+/// Looks for `k` entries of `sorted[chosen.last() + 1..]` summing to
+/// `target`, pushing their positions in `sorted` onto `chosen`.
+///
+/// Sums are kept in `i128` so that no combination of `i64` entries overflows.
+fn search(sorted: &[(i64, usize)], k: usize, target: i128, chosen: &mut Vec<usize>) -> bool {
+    let start = chosen.last().map_or(0, |&i| i + 1);
+    let value = |i: usize| i128::from(sorted[i].0);
+    match k {
+        0 => target == 0,
+        1 => match sorted[start..].binary_search_by(|&(v, _)| i128::from(v).cmp(&target)) {
+            Ok(i) => {
+                chosen.push(start + i);
+                true
+            }
+            Err(_) => false,
+        },
+        2 => {
+            let (mut lo, mut hi) = (start, sorted.len());
+            while lo + 1 < hi {
+                let sum = value(lo) + value(hi - 1);
+                if sum == target {
+                    chosen.extend([lo, hi - 1]);
+                    return true;
+                } else if sum < target {
+                    lo += 1;
+                } else {
+                    hi -= 1;
+                }
+            }
+            false
+        }
+        _ => {
+            for i in start..sorted.len() {
+                chosen.push(i);
+                if search(sorted, k - 1, target - value(i), chosen) {
+                    return true;
+                }
+                chosen.pop();
+            }
+            false
+        }
+    }
+}
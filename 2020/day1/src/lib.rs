@@ -0,0 +1,43 @@
+use aoc2020_common::{Answer, Solution};
+use anyhow::{anyhow, Result};
+
+pub mod index;
+pub mod ksum;
+
+pub use index::SumIndex;
+pub use ksum::{all_k_sums, count_k_sums, find_k_sum, KSum, NoSolution};
+
+#[cfg(test)]
+mod test;
+
+/// What the expense report entries should add up to.
+pub const TARGET: i64 = 2020;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Parsed<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input
+            .lines()
+            .map(str::parse::<i64>)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    fn part1(numbers: &Vec<i64>) -> Result<Answer> {
+        answer(find_k_sum(numbers, 2, TARGET)?)
+    }
+
+    fn part2(numbers: &Vec<i64>) -> Result<Answer> {
+        answer(find_k_sum(numbers, 3, TARGET)?)
+    }
+}
+
+fn answer(sum: KSum) -> Result<Answer> {
+    let product = sum
+        .product()
+        .ok_or_else(|| anyhow!("the product of {:?} overflows", sum.values))?;
+    Ok(Answer::Number(product))
+}
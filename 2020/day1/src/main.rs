@@ -1,29 +1,24 @@
-use anyhow;
-use itertools::Itertools;
+use aoc2020_common::input::Source;
+use aoc2020_common::{run_with, take_option, Args, Solution};
+use anyhow::{bail, Context};
+use day1::{Day1, SumIndex};
 
+/// Solves the puzzle, or with `--queries <FILE>` answers the pair and triple
+/// queries in FILE against the input instead.
 fn main() -> anyhow::Result<()> {
-    // Part 1
-    let (a,b) = include_str!("input.txt")
-        .lines()
-        .map(str::parse::<i64>)
-        .collect::<Result<Vec<_>,_>>()?
-        .into_iter()
-        .tuple_combinations()
-        .find(|(a,b)| a + b == 2020)
-        .expect("No pair in the input sums to 2020");
-    dbg!(a + b);
-    dbg!(a * b);
+    let mut args: Vec<_> = std::env::args_os().skip(1).collect();
+    let queries = take_option(&mut args, "--queries")?;
+    let args = Args::parse(args)?;
+    let queries = match queries {
+        Some(queries) => queries,
+        None => return run_with::<Day1>(args),
+    };
+    if args.part.is_some() {
+        bail!("--part cannot be combined with --queries");
+    }
 
-    // Part 2
-    let (a,b, c) = include_str!("input.txt")
-    .lines()
-    .map(str::parse::<i64>)
-    .collect::<Result<Vec<_>,_>>()?
-    .into_iter()
-    .tuple_combinations()
-    .find(|(a,b, c)| a + b + c == 2020)
-    .expect("No triplet in the input sums to 2020");
-    dbg!(a + b + c);
-    dbg!(a * b * c);
-    Ok(())
+    let numbers = Day1::parse(&Source::resolve(Day1::DAY, args.input).read()?)?;
+    let queries = std::fs::read_to_string(&queries)
+        .with_context(|| format!("could not read queries from {:?}", queries))?;
+    SumIndex::new(&numbers).answer_queries(&queries, &mut std::io::stdout().lock())
 }
@@ -0,0 +1,132 @@
+use super::*;
+use aoc2020_common::solve;
+
+#[test]
+fn test_example() {
+    let [part1, part2] = solve::<Day1>(include_str!("../../fixtures/example.txt")).unwrap();
+    assert_eq!(part1.unwrap(), Answer::Number(514579));
+    assert_eq!(part2.unwrap(), Answer::Number(241861950));
+}
+
+#[test]
+fn test_no_pair() {
+    let numbers = Day1::parse("1\n2\n3").unwrap();
+    assert!(Day1::part1(&numbers).is_err());
+    assert!(Day1::part2(&numbers).is_err());
+}
+
+#[test]
+fn test_find_k_sum() {
+    let numbers = [1721, 979, 366, 299, 675, 1456];
+    let pair = find_k_sum(&numbers, 2, 2020).unwrap();
+    assert_eq!(pair.indices, vec![0, 3]);
+    assert_eq!(pair.values, vec![1721, 299]);
+    let triplet = find_k_sum(&numbers, 3, 2020).unwrap();
+    assert_eq!(triplet.values, vec![979, 366, 675]);
+    assert_eq!(
+        find_k_sum(&numbers, 4, 1721 + 979 + 366 + 299)
+            .unwrap()
+            .indices,
+        vec![0, 1, 2, 3]
+    );
+    assert_eq!(find_k_sum(&numbers, 1, 675).unwrap().indices, vec![4]);
+    assert_eq!(find_k_sum(&numbers, 0, 0).unwrap().indices, vec![]);
+    assert_eq!(
+        find_k_sum(&numbers, 7, 0),
+        Err(NoSolution { k: 7, target: 0 })
+    );
+}
+
+#[test]
+fn test_product_overflow() {
+    let numbers = [3_000_000_000_000, 4_000_000_000_000];
+    let pair = find_k_sum(&numbers, 2, 7_000_000_000_000).unwrap();
+    assert_eq!(pair.product(), Some(12_000_000_000_000_000_000_000_000));
+
+    let numbers = [i64::MAX, i64::MIN + 1, i64::MAX];
+    let triplet = find_k_sum(&numbers, 3, i64::MAX).unwrap();
+    assert_eq!(triplet.product(), None);
+    assert!(Day1::part2(&numbers.to_vec()).is_err());
+}
+
+#[test]
+fn test_find_k_sum_uses_entries_once() {
+    assert!(find_k_sum(&[1010, 3], 2, 2020).is_err());
+    assert_eq!(
+        find_k_sum(&[1010, 3, 1010], 2, 2020).unwrap().indices,
+        vec![0, 2]
+    );
+    assert_eq!(
+        find_k_sum(&[-5, 10, i64::MAX, i64::MIN], 2, -1)
+            .unwrap()
+            .values,
+        vec![i64::MAX, i64::MIN]
+    );
+}
+
+#[test]
+fn test_all_k_sums() {
+    let numbers = [1, 5, 3, 3, 4, 2, 5, 0];
+    assert_eq!(
+        all_k_sums(&numbers, 2, 6).unwrap(),
+        vec![vec![1, 5], vec![2, 4], vec![3, 3]]
+    );
+    assert_eq!(
+        all_k_sums(&numbers, 3, 8).unwrap(),
+        vec![vec![0, 3, 5], vec![1, 2, 5], vec![1, 3, 4], vec![2, 3, 3]]
+    );
+    assert_eq!(count_k_sums(&numbers, 2, 6), 3);
+    assert_eq!(count_k_sums(&numbers, 3, 8), 4);
+    assert_eq!(count_k_sums(&numbers, 2, 10), 1);
+}
+
+#[test]
+fn test_all_k_sums_no_solution() {
+    assert_eq!(
+        all_k_sums(&[1010, 3], 2, 2020),
+        Err(NoSolution { k: 2, target: 2020 })
+    );
+    assert_eq!(count_k_sums(&[1010, 3], 2, 2020), 0);
+    assert_eq!(
+        Day1::part1(&vec![1, 2]).unwrap_err().to_string(),
+        "no 2 entries of the input sum to 2020"
+    );
+}
+
+#[test]
+fn test_sum_index() {
+    let numbers = Day1::parse(include_str!("../../fixtures/example.txt")).unwrap();
+    let index = SumIndex::new(&numbers);
+    for target in [2020, 1020, 1721 + 979, 4000] {
+        assert_eq!(index.pair(target), find_k_sum(&numbers, 2, target));
+    }
+    for target in [2020, 366 + 299 + 675, 979 + 1721 + 1456, 10] {
+        assert_eq!(
+            index.triple(target).map(|t| t.values),
+            find_k_sum(&numbers, 3, target).map(|t| t.values)
+        );
+    }
+    assert!(SumIndex::new(&[1010, 3]).pair(2020).is_err());
+    assert!(SumIndex::new(&[1010, 5, 1000]).triple(2020).is_err());
+}
+
+#[test]
+fn test_answer_queries() {
+    let numbers = Day1::parse(include_str!("../../fixtures/example.txt")).unwrap();
+    let mut out = Vec::new();
+    SumIndex::new(&numbers)
+        .answer_queries("2 2020\n3 2020\n\n2 1\n", &mut out)
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "1721 299\n979 366 675\nnone\n"
+    );
+
+    let err = SumIndex::new(&numbers)
+        .answer_queries("2 2020\n4 2020\n", &mut Vec::new())
+        .unwrap_err();
+    assert_eq!(
+        format!("{:#}", err),
+        "query on line 2: tuple size must be 2 or 3, got \"4\""
+    );
+}
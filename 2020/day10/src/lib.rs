@@ -0,0 +1,70 @@
+use aoc2020_common::{Answer, Solution};
+use anyhow::{anyhow, bail, Result};
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod test;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Results {
+    pub ones: usize,
+    pub threes: usize,
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    /// The outlet, every adapter sorted by joltage, and the device.
+    type Parsed<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let mut numbers = std::iter::once(Ok(0))
+            .chain(input.lines().map(str::parse))
+            .collect::<Result<Vec<usize>, _>>()?;
+        numbers.sort_unstable();
+        if let Some(&max) = numbers.iter().max() {
+            numbers.push(max + 3);
+        }
+        Ok(numbers)
+    }
+
+    fn part1(numbers: &Vec<usize>) -> Result<Answer> {
+        let mut answer = Results::default();
+        for w in numbers.windows(2) {
+            match w[1] - w[0] {
+                1 => answer.ones += 1,
+                3 => answer.threes += 1,
+                gap => bail!("Found invalid gap {} between joltages", gap),
+            }
+        }
+        // effective jolt value
+        Ok((answer.ones * answer.threes).into())
+    }
+
+    fn part2(numbers: &Vec<usize>) -> Result<Answer> {
+        // number of joltage adapter configurations
+        let n = numbers.len();
+        let mut num_paths = HashMap::new();
+        num_paths.insert(numbers.last().copied().unwrap(), 1);
+        for i in (0..(n - 1)).rev() {
+            let i_val = numbers[i];
+            let range = (i + 1)..=(std::cmp::min(i + 3, n - 1));
+            let neighbor_paths: usize = range.filter_map(|j| {
+                let j_val = numbers[j];
+                let gap = j_val - i_val;
+                if (1..=3).contains(&gap) {
+                    Some(num_paths.get(&j_val).unwrap())
+                } else {
+                    None
+                }
+            }).sum();
+            num_paths.insert(i_val,neighbor_paths);
+        }
+        let answer = num_paths
+            .get(&0)
+            .copied()
+            .ok_or_else(|| anyhow!("no path from the outlet"))?;
+        Ok(answer.into())
+    }
+}
@@ -0,0 +1,16 @@
+use super::*;
+use aoc2020_common::solve;
+
+#[test]
+fn test_example() {
+    let [part1, part2] = solve::<Day10>(include_str!("../../fixtures/example.txt")).unwrap();
+    assert_eq!(part1.unwrap(), Answer::Number(7 * 5));
+    assert_eq!(part2.unwrap(), Answer::Number(8));
+}
+
+#[test]
+fn test_example2() {
+    let [part1, part2] = solve::<Day10>(include_str!("../../fixtures/example2.txt")).unwrap();
+    assert_eq!(part1.unwrap(), Answer::Number(22 * 10));
+    assert_eq!(part2.unwrap(), Answer::Number(19208));
+}
@@ -0,0 +1,163 @@
+use std::fmt::Debug;
+
+use aoc2020_common::grid::{Grid, GridError};
+use aoc2020_common::{Answer, Solution, Vec2};
+use anyhow::Result;
+
+#[cfg(test)]
+mod test;
+
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum Tile {
+    #[default]
+    Floor,
+    EmptySeat,
+    OccupiedSeat,
+}
+
+impl Debug for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Floor => write!(f, "."),
+            Self::EmptySeat => write!(f, "L"),
+            Self::OccupiedSeat => write!(f, "#"),
+        }
+    }
+}
+
+impl Tile {
+    /// The tile after one round, where an occupied seat empties once at least
+    /// `tolerance` of `neighbors` are occupied.
+    pub fn next<I>(&self, neighbors: I, tolerance: usize) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        match self {
+            Self::Floor => Self::Floor,
+            Self::EmptySeat => match neighbors
+                .filter(|t| matches!(t, Self::OccupiedSeat))
+                .count()
+            {
+                0 => Self::OccupiedSeat,
+                _ => Self::EmptySeat,
+            },
+            Self::OccupiedSeat => {
+                if neighbors
+                    .filter(|t| matches!(t, Self::OccupiedSeat))
+                    .count()
+                    < tolerance
+                {
+                    Self::OccupiedSeat
+                } else {
+                    Self::EmptySeat
+                }
+            }
+        }
+    }
+}
+
+impl Tile {
+    fn parse(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Floor),
+            '#' => Some(Self::OccupiedSeat),
+            'L' => Some(Self::EmptySeat),
+            _ => None,
+        }
+    }
+}
+
+#[derive(PartialEq, Clone)]
+pub struct SeatingMap {
+    grid: Grid<Tile>,
+}
+
+impl SeatingMap {
+    pub fn parse(input: &str) -> Result<Self, GridError> {
+        Ok(Self {
+            grid: Grid::parse(input, Tile::parse)?,
+        })
+    }
+
+    pub fn get(&self, pos: Vec2<i64>) -> Option<Tile> {
+        self.grid.get(pos).copied()
+    }
+
+    pub fn neighbor_tiles(&self, pos: Vec2<i64>) -> impl Iterator<Item = Tile> + '_ {
+        self.grid.neighbors8(pos).map(|(_, &tile)| tile)
+    }
+
+    pub fn next(&self) -> Self {
+        Self {
+            grid: self.grid.map(|pos, tile| tile.next(self.neighbor_tiles(pos), 4)),
+        }
+    }
+
+    pub fn next_extended(&self) -> Self {
+        Self {
+            grid: self.grid.map(|pos, tile| tile.next(self.visible_seats(pos), 5)),
+        }
+    }
+
+    pub fn last(self) -> Self {
+        use itertools::Itertools;
+        itertools::iterate(self, SeatingMap::next)
+            .tuple_windows()
+            .find_map(|(prev, next)| if prev == next { Some(next) } else { None })
+            .unwrap()
+    }
+
+    pub fn last_extended(self) -> Self {
+        use itertools::Itertools;
+        itertools::iterate(self, SeatingMap::next_extended)
+            .tuple_windows()
+            .find_map(|(prev, next)| if prev == next { Some(next) } else { None })
+            .unwrap()
+    }
+
+    pub fn occupied_seats(&self) -> usize {
+        self.grid
+            .iter()
+            .filter(|(_, tile)| matches!(tile, Tile::OccupiedSeat))
+            .count()
+    }
+
+    pub fn visible_seats(&self, pos: Vec2<i64>) -> impl Iterator<Item = Tile> + '_ {
+        use itertools::Itertools;
+        Vec2::offsets8()
+        .into_iter()
+        .flat_map(move |delta| {
+            itertools::iterate(pos, move |&v| v + delta)
+            .skip(1)
+            .map(|pos| self.get(pos))
+            .while_some()
+            .filter(|tile| !matches!(tile, Tile::Floor))
+            .take(1)
+        })
+    }
+}
+
+impl Debug for SeatingMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.grid)
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Parsed<'a> = SeatingMap;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(SeatingMap::parse(input)?)
+    }
+
+    fn part1(map: &SeatingMap) -> Result<Answer> {
+        Ok(map.clone().last().occupied_seats().into())
+    }
+
+    fn part2(map: &SeatingMap) -> Result<Answer> {
+        Ok(map.clone().last_extended().occupied_seats().into())
+    }
+}
@@ -0,0 +1,18 @@
+use super::*;
+use aoc2020_common::solve;
+
+const EXAMPLE: &str = include_str!("../../fixtures/example.txt");
+
+#[test]
+fn test_example() {
+    let [part1, part2] = solve::<Day11>(EXAMPLE).unwrap();
+    assert_eq!(part1.unwrap(), Answer::Number(37));
+    assert_eq!(part2.unwrap(), Answer::Number(26));
+}
+
+#[test]
+fn test_first_round() {
+    // every seat is empty at first, so every seat fills up
+    let map = SeatingMap::parse(EXAMPLE).unwrap().next();
+    assert_eq!(format!("{:?}", map), EXAMPLE.replace('L', "#"));
+}
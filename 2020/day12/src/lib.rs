@@ -0,0 +1,168 @@
+use aoc2020_common::{Answer, Solution, Vec2};
+use anyhow::{anyhow, bail, Context, Result};
+
+#[cfg(test)]
+mod test;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum Direction {
+    East = 0,
+    South = 1,
+    West = 2,
+    North = 3,
+}
+
+impl Direction {
+    pub fn offset(&self) -> Vec2<isize> {
+        match &self {
+            Direction::East => Vec2 { x: 1, y: 0 },
+            Direction::South => Vec2 { x: 0, y: -1 },
+            Direction::West => Vec2 { x: -1, y: 0 },
+            Direction::North => Vec2 { x: 0, y: 1 },
+        }
+    }
+}
+
+impl From<Direction> for isize {
+    fn from(dir: Direction) -> Self {
+        dir as _
+    }
+}
+
+impl std::convert::TryFrom<isize> for Direction {
+    type Error = &'static str;
+
+    fn try_from(value: isize) -> Result<Self, Self::Error> {
+        if (0..=3).contains(&value) {
+            Ok(unsafe {
+                std::mem::transmute::<u8, Direction>(value as u8)
+            })
+        } else {
+            Err("Direction out of bounds")
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AngleDelta(pub isize);
+
+impl std::ops::Add<AngleDelta> for Direction {
+    type Output = Self;
+
+    fn add(self, rhs: AngleDelta) -> Self::Output {
+        let angle: isize = self.into();
+        (angle + rhs.0).rem_euclid(4).try_into().unwrap()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ShipState {
+    pub pos: Vec2<isize>,
+    pub dir: Direction,
+    pub waypoint: Vec2<isize>,
+}
+
+impl std::ops::Add<Instruction> for ShipState {
+    type Output = Self;
+
+    fn add(self, rhs: Instruction) -> Self::Output {
+        match rhs {
+            Instruction::Move(dir, units) => Self {
+                waypoint: self.waypoint + dir.offset() * units,
+                ..self
+            },
+            Instruction::Rotate(delta) => Self {
+                waypoint: self.waypoint.rotate(delta.0),
+                ..self
+            },
+            Instruction::Advance(units) => Self {
+                pos: self.pos + self.waypoint * units,
+                ..self
+            },
+        }
+    }
+}
+
+impl ShipState {
+    /// Applies an instruction to the ship itself, ignoring the waypoint.
+    pub fn sail(self, ins: Instruction) -> Self {
+        match ins {
+            Instruction::Move(dir, units) => Self {
+                pos: self.pos + dir.offset() * units,
+                ..self
+            },
+            Instruction::Rotate(delta) => Self {
+                dir: self.dir + delta,
+                ..self
+            },
+            Instruction::Advance(units) => Self {
+                pos: self.pos + self.dir.offset() * units,
+                ..self
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Instruction {
+    Move(Direction, isize),
+    Rotate(AngleDelta),
+    Advance(isize),
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction> {
+    let mut chars = line.chars();
+    let command = chars.next().ok_or_else(|| anyhow!("empty instruction"))?;
+    let number: isize = chars
+        .as_str()
+        .parse()
+        .with_context(|| format!("invalid number in {:?}", line))?;
+
+    Ok(match command {
+        'N' => Instruction::Move(Direction::North, number),
+        'S' => Instruction::Move(Direction::South, number),
+        'E' => Instruction::Move(Direction::East, number),
+        'W' => Instruction::Move(Direction::West, number),
+        'L' | 'R' if number % 90 != 0 => bail!("cannot turn by {} degrees", number),
+        'L' => Instruction::Rotate(AngleDelta(-number / 90)),
+        'R' => Instruction::Rotate(AngleDelta(number / 90)),
+        'F' => Instruction::Advance(number),
+        c => bail!("unknown instruction {:?}", c),
+    })
+}
+
+/// Parses one instruction per line, failing on the first bad one.
+pub fn parse_instructions(input: &str) -> impl Iterator<Item = Result<Instruction>> + '_ {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_instruction(line).with_context(|| format!("line {}", i + 1)))
+}
+
+pub const START: ShipState = ShipState {
+    dir: Direction::East,
+    pos: Vec2 { x: 0, y: 0 },
+    waypoint: Vec2 { x: 10, y: 1 },
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Parsed<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse_instructions(input).collect()
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Result<Answer> {
+        let end = instructions.iter().fold(START, |state, &ins| state.sail(ins));
+        Ok(end.pos.manhattan_distance(START.pos).into())
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Result<Answer> {
+        let end = instructions.iter().fold(START, |state, &ins| state + ins);
+        Ok(end.pos.manhattan_distance(START.pos).into())
+    }
+}
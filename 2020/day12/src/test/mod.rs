@@ -0,0 +1,27 @@
+use super::*;
+use aoc2020_common::solve;
+
+#[test]
+fn test_example() {
+    let [part1, part2] = solve::<Day12>(include_str!("../../fixtures/example.txt")).unwrap();
+    assert_eq!(part1.unwrap(), Answer::Number(25));
+    assert_eq!(part2.unwrap(), Answer::Number(286));
+}
+
+#[test]
+fn test_sail() {
+    let state = START.sail(Instruction::Rotate(AngleDelta(1)));
+    assert_eq!(state.dir, Direction::South);
+    let state = state.sail(Instruction::Advance(3));
+    assert_eq!(state.pos, Vec2::new(0, -3));
+    assert_eq!(state.waypoint, START.waypoint);
+}
+
+#[test]
+fn test_malformed() {
+    for input in ["F10\n\nN3", "F10\nX3", "Ften", "R45", "F99999999999999999999"] {
+        assert!(Day12::parse(input).is_err(), "{:?}", input);
+    }
+    let error = Day12::parse("F10\n\nN3").unwrap_err();
+    assert_eq!(format!("{:#}", error), "line 2: empty instruction");
+}
@@ -0,0 +1,205 @@
+use aoc2020_common::{Answer, Solution};
+use anyhow::Result;
+use serde::Serialize;
+use std::fmt;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub mod report;
+pub mod rules;
+
+use rules::{CountRange, Policy, PositionXor, Rule};
+
+/// Why a line of the database could not be checked.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum PolicyError {
+    #[error("malformed line: {0}")]
+    Malformed(String),
+
+    #[error("position {position} is outside the {len}-character password")]
+    BadPosition { position: usize, len: usize },
+}
+
+/// A `PolicyError` on a 1-based line of the input.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("line {line}: {error}")]
+pub struct BadLine {
+    pub line: usize,
+    pub error: PolicyError,
+}
+
+/// What a position or a length in a password counts.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Segmentation {
+    /// Unicode scalar values, so `é` is one character, but `e` followed by a
+    /// combining accent is two.
+    #[default]
+    Chars,
+    /// Extended grapheme clusters, so an `e` with a combining accent is one
+    /// character, which is not `e`.
+    Graphemes,
+}
+
+/// The policy at the start of a line: a letter and two numbers, whose
+/// meaning is up to the rule checking the password.
+#[derive(PartialEq, Eq, Debug, Serialize)]
+pub struct PasswordPolicy {
+    pub char: char,
+    pub first: usize,
+    pub second: usize,
+    #[serde(skip)]
+    pub segmentation: Segmentation,
+}
+
+/// Formats the policy the way it is written in the input, such as `1-3 a`.
+impl fmt::Display for PasswordPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{} {}", self.first, self.second, self.char)
+    }
+}
+
+impl PasswordPolicy {
+    /// The characters of `password`, as counted by `segmentation`.
+    pub fn split<'p>(&self, password: &'p str) -> Vec<&'p str> {
+        match self.segmentation {
+            Segmentation::Chars => password
+                .char_indices()
+                .map(|(i, c)| &password[i..i + c.len_utf8()])
+                .collect(),
+            Segmentation::Graphemes => password.graphemes(true).collect(),
+        }
+    }
+
+    /// Whether a character from `split` is the letter.
+    fn is_letter(&self, c: &str) -> bool {
+        c.chars().eq([self.char])
+    }
+
+    /// How many times the letter occurs in `password`.
+    pub fn count(&self, password: &str) -> usize {
+        self.split(password)
+            .into_iter()
+            .filter(|c| self.is_letter(c))
+            .count()
+    }
+
+    /// Whether the letter is at the 1-based `position` of `password`.
+    pub fn is_at(&self, password: &str, position: usize) -> Result<bool, PolicyError> {
+        let chars = self.split(password);
+        position
+            .checked_sub(1)
+            .and_then(|i| chars.get(i))
+            .map(|c| self.is_letter(c))
+            .ok_or(PolicyError::BadPosition {
+                position,
+                len: chars.len(),
+            })
+    }
+
+    pub fn is_valid(&self, password: &str) -> bool {
+        matches!(CountRange.check(self, password), Ok(true))
+    }
+
+    pub fn is_valid_in_position(&self, password: &str) -> Result<bool, PolicyError> {
+        PositionXor.check(self, password)
+    }
+}
+
+/// One well-formed line of the database.
+#[derive(PartialEq, Eq, Debug)]
+pub struct Entry<'a> {
+    /// 1-based, as in an editor.
+    pub line: usize,
+    pub policy: PasswordPolicy,
+    pub password: &'a str,
+}
+
+/// The lines of the input, split into the ones that parsed and the ones
+/// that did not.
+#[derive(PartialEq, Eq, Debug, Default)]
+pub struct Database<'a> {
+    pub entries: Vec<Entry<'a>>,
+    pub bad_lines: Vec<BadLine>,
+}
+
+impl<'a> Database<'a> {
+    /// Parses every line, setting aside the bad ones instead of stopping.
+    pub fn parse(input: &'a str) -> Self {
+        Self::parse_with(input, Segmentation::default())
+    }
+
+    /// Like `parse`, with every policy counting characters as `segmentation`
+    /// does.
+    pub fn parse_with(input: &'a str, segmentation: Segmentation) -> Self {
+        let mut res = Self::default();
+        for (line, text) in (1..).zip(input.lines()) {
+            match parse_line(text) {
+                Ok((policy, password)) => res.entries.push(Entry {
+                    line,
+                    policy: PasswordPolicy {
+                        segmentation,
+                        ..policy
+                    },
+                    password,
+                }),
+                Err(error) => res.bad_lines.push(BadLine { line, error }),
+            }
+        }
+        res
+    }
+}
+
+pub fn parse_line(line: &str) -> Result<(PasswordPolicy, &str), PolicyError> {
+    peg::parser! {
+        grammar parser() for str {
+            rule number() -> usize
+            = n:$(['0'..='9']+) {? n.parse().or(Err("number")) }
+
+            rule letter() -> char
+            = [c if !c.is_whitespace() && c != ':']
+
+            rule password() -> &'input str
+            = letters:$([_]*) { letters }
+
+            pub(crate) rule line() -> (PasswordPolicy, &'input str)
+            = first:number() "-" second:number() " " char:letter() ": " password:password() {
+                (PasswordPolicy {char, first, second, segmentation: Segmentation::default()}, password)
+            }
+        }
+    }
+    parser::line(line).map_err(|e| PolicyError::Malformed(e.to_string()))
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Parsed<'a> = Database<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(Database::parse(input))
+    }
+
+    fn part1(db: &Database<'_>) -> Result<Answer> {
+        Ok(Policy::sled_rental().audit(db).valid.into())
+    }
+
+    fn part2(db: &Database<'_>) -> Result<Answer> {
+        Ok(Policy::toboggan().audit(db).valid.into())
+    }
+
+    /// The lines that did not parse, then the ones that could not be checked
+    /// under either part's policy.
+    fn warnings(db: &Database<'_>) -> Vec<String> {
+        let errors = Policy::puzzle().into_iter().flat_map(|policy| {
+            policy
+                .audit(db)
+                .errors
+                .into_iter()
+                .map(move |bad| format!("{} ({})", bad, policy.name))
+        });
+        db.bad_lines.iter().map(ToString::to_string).chain(errors).collect()
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -1,98 +1,67 @@
-use anyhow::Result;
-use peg::*;
-use std::ops::RangeInclusive;
+use aoc2020_common::input::Source;
+use aoc2020_common::{run_with, take_flag, take_option, Args, Solution};
+use anyhow::bail;
+use day2::report::{report, write_json_lines, write_table};
+use day2::rules::{load_policies, Policy};
+use day2::{Database, Day2, Segmentation};
+use std::path::Path;
 
-#[derive(PartialEq, Debug)]
-struct PasswordPolicy {
-    char: u8,
-    range: RangeInclusive<usize>,
-    positions: (usize, usize),
-}
-
-impl PasswordPolicy {
-    fn is_valid(&self, password: &str) -> bool {
-        self.range.contains(
-            &password
-                .as_bytes()
-                .iter()
-                .copied()
-                .filter(|&letter| letter == self.char)
-                .count(),
-        )
+/// Solves the puzzle, or checks every password against the puzzle's
+/// policies, or against the rule sets in `--policies <FILE>`, and prints:
+///
+/// - with `--report <table|jsonl>`, every line with the reason it fails
+///   each policy;
+/// - otherwise, how many passwords pass each policy.
+///
+/// With `--graphemes`, positions and lengths count grapheme clusters instead
+/// of Unicode scalar values.
+fn main() -> anyhow::Result<()> {
+    let mut args: Vec<_> = std::env::args_os().skip(1).collect();
+    let policies = take_option(&mut args, "--policies")?;
+    let format = take_option(&mut args, "--report")?;
+    let segmentation = match take_flag(&mut args, "--graphemes") {
+        true => Segmentation::Graphemes,
+        false => Segmentation::Chars,
+    };
+    let args = Args::parse(args)?;
+    if policies.is_none() && format.is_none() && segmentation == Segmentation::Chars {
+        return run_with::<Day2>(args);
     }
-
-    fn is_valid_in_position(&self, password: &str) -> bool {
-        let is_at_first_positions = password
-            .as_bytes()
-            .get(self.positions.0 - 1)
-            .copied()
-            .unwrap()
-            == self.char;
-        let is_at_second_positions = password
-            .as_bytes()
-            .get(self.positions.1 - 1)
-            .copied()
-            .unwrap()
-            == self.char;
-        is_at_first_positions ^ is_at_second_positions
+    if args.part.is_some() {
+        bail!("--part cannot be combined with --policies, --report or --graphemes");
     }
-}
-
-fn parse_line(line: &str, with_position: bool) -> Result<(PasswordPolicy, &str)> {
-    peg::parser! {
-        grammar parser() for str {
-            rule number() -> usize
-            = n:$(['0'..='9']+) { n.parse().unwrap() }
-
-            rule range() -> RangeInclusive<usize>
-            = min:number() "-" max:number() { min..=max }
-
-            rule positions() -> (usize, usize)
-            = min:number() "-" max:number() { (min, max) }
-
-            rule byte() -> u8
-            = letter:$(['a'..='z']) { letter.as_bytes()[0] }
+    let policies = match policies {
+        Some(path) => load_policies(Path::new(&path))?,
+        None => Policy::puzzle(),
+    };
 
-            rule password() -> &'input str
-            = letters:$([_]*) { letters }
-
-            pub(crate) rule line() -> (PasswordPolicy, &'input str)
-            = range:range() " " byte:byte() ": " password:password() {
-                (PasswordPolicy {range, char: byte, positions: (0,0)}, password)
-            }
-
-            pub(crate) rule line_with_positions() -> (PasswordPolicy, &'input str)
-            = positions:positions() " " byte:byte() ": " password:password() {
-                (PasswordPolicy {range: 0..=1, char: byte, positions}, password)
+    let input = Source::resolve(Day2::DAY, args.input).read()?;
+    let db = Database::parse_with(&input, segmentation);
+    let mut out = std::io::stdout().lock();
+    match format.as_ref().map(|f| f.to_str()) {
+        None => {
+            for policy in &policies {
+                let audit = policy.audit(&db);
+                println!(
+                    "{}: {} of {} passwords valid",
+                    policy.name,
+                    audit.valid,
+                    db.entries.len()
+                );
+                for bad in audit.errors {
+                    eprintln!("  {}", bad);
+                }
             }
         }
+        Some(Some("table")) => write_table(&report(&db, &policies), &policies, &mut out)?,
+        Some(Some("jsonl")) => write_json_lines(&report(&db, &policies), &mut out)?,
+        Some(_) => bail!("--report must be table or jsonl, got {:?}", format.unwrap()),
     }
-    if !with_position {
-        Ok(parser::line(line)?)
-    } else {
-        Ok(parser::line_with_positions(line)?)
+    if !db.bad_lines.is_empty() {
+        eprintln!("skipped {} bad line(s):", db.bad_lines.len());
+        for bad in &db.bad_lines {
+            eprintln!("  {}", bad);
+        }
     }
+    Ok(())
 }
-
-fn main() {
-    // Part 1
-    let count = include_str!("input.txt")
-        .lines()
-        .map(|l| parse_line(l, false))
-        .map(std::result::Result::unwrap)
-        .filter(|(policy, password)| policy.is_valid(password))
-        .count();
-    println!("{} passwords are valid", count);
-
-    // Part 2
-    let count = include_str!("input.txt")
-    .lines()
-    .map(|l| parse_line(l, true))
-    .map(std::result::Result::unwrap)
-    .filter(|(policy,password)| policy.is_valid_in_position(password))
-    .count();
-    println!("{} passwords are valid", count);
-}
-
-#[cfg(test)]
-mod test;
@@ -0,0 +1,108 @@
+//! A line-by-line account of which policies each password passes, and why
+//! it fails the others.
+
+use crate::rules::Policy;
+use crate::{Database, PasswordPolicy};
+use anyhow::Result;
+use serde::Serialize;
+use std::io::Write;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// How one password fared under one policy. Passwords that cannot be
+/// checked are not valid, with the error as the reason.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Verdict<'a> {
+    pub policy: &'a str,
+    pub valid: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// How one line of the database fared under every policy.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct LineReport<'a> {
+    /// 1-based, as in an editor.
+    pub line: usize,
+    pub policy: &'a PasswordPolicy,
+    pub password: &'a str,
+    pub verdicts: Vec<Verdict<'a>>,
+}
+
+/// Checks every well-formed entry, in input order, against every policy.
+pub fn report<'a>(db: &'a Database<'a>, policies: &'a [Policy]) -> Vec<LineReport<'a>> {
+    db.entries
+        .iter()
+        .map(|entry| LineReport {
+            line: entry.line,
+            policy: &entry.policy,
+            password: entry.password,
+            verdicts: policies
+                .iter()
+                .map(|p| {
+                    let reason = match p.rule.violation(&entry.policy, entry.password) {
+                        Ok(reason) => reason,
+                        Err(e) => Some(e.to_string()),
+                    };
+                    Verdict {
+                        policy: &p.name,
+                        valid: reason.is_none(),
+                        reason,
+                    }
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Writes one JSON object per line.
+pub fn write_json_lines(reports: &[LineReport<'_>], out: &mut impl Write) -> Result<()> {
+    for report in reports {
+        serde_json::to_writer(&mut *out, report)?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+/// Writes an aligned table with a column per policy, holding `ok` or the
+/// reason the password fails it.
+pub fn write_table(
+    reports: &[LineReport<'_>],
+    policies: &[Policy],
+    out: &mut impl Write,
+) -> Result<()> {
+    let header = ["line", "policy", "password"]
+        .into_iter()
+        .map(String::from)
+        .chain(policies.iter().map(|p| p.name.clone()))
+        .collect();
+    let rows: Vec<Vec<String>> = std::iter::once(header)
+        .chain(reports.iter().map(|r| {
+            [r.line.to_string(), r.policy.to_string(), r.password.into()]
+                .into_iter()
+                .chain(r.verdicts.iter().map(|v| match &v.reason {
+                    Some(reason) => reason.clone(),
+                    None => "ok".into(),
+                }))
+                .collect()
+        }))
+        .collect();
+
+    // pad by grapheme clusters so combining accents do not push columns
+    // out of line
+    let width = |cell: &str| cell.graphemes(true).count();
+    let mut widths = vec![0; rows[0].len()];
+    for row in &rows {
+        for (max, cell) in widths.iter_mut().zip(row) {
+            *max = (*max).max(width(cell));
+        }
+    }
+    for row in &rows {
+        let cells: Vec<_> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &max)| format!("{}{}", cell, " ".repeat(max - width(cell))))
+            .collect();
+        writeln!(out, "{}", cells.join("  ").trim_end())?;
+    }
+    Ok(())
+}
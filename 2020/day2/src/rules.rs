@@ -0,0 +1,317 @@
+//! Rules a password can be checked against, and rule sets loaded from a
+//! config file.
+
+use crate::{BadLine, Database, PasswordPolicy, PolicyError};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fmt::Debug;
+use std::path::Path;
+
+/// A check of a password against the policy on its line.
+pub trait Rule: Debug {
+    /// Why the password fails the rule, or `None` if it passes.
+    ///
+    /// Fails if the policy makes no sense under this rule, such as a
+    /// position past the end of the password.
+    fn violation(
+        &self,
+        policy: &PasswordPolicy,
+        password: &str,
+    ) -> Result<Option<String>, PolicyError>;
+
+    fn check(&self, policy: &PasswordPolicy, password: &str) -> Result<bool, PolicyError> {
+        Ok(self.violation(policy, password)?.is_none())
+    }
+}
+
+/// The policy's letter occurs between `first` and `second` times, inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CountRange;
+
+impl Rule for CountRange {
+    fn violation(
+        &self,
+        policy: &PasswordPolicy,
+        password: &str,
+    ) -> Result<Option<String>, PolicyError> {
+        let count = policy.count(password);
+        let allowed = policy.first..=policy.second;
+        Ok((!allowed.contains(&count)).then(|| {
+            format!(
+                "found {} {:?}, allowed {:?}",
+                count,
+                policy.char,
+                allowed
+            )
+        }))
+    }
+}
+
+/// The policy's letter is at exactly one of the 1-based positions `first`
+/// and `second`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PositionXor;
+
+impl Rule for PositionXor {
+    fn violation(
+        &self,
+        policy: &PasswordPolicy,
+        password: &str,
+    ) -> Result<Option<String>, PolicyError> {
+        let c = policy.char;
+        Ok(match (
+            policy.is_at(password, policy.first)?,
+            policy.is_at(password, policy.second)?,
+        ) {
+            (true, true) => Some(format!(
+                "{:?} at both positions {} and {}",
+                c, policy.first, policy.second
+            )),
+            (false, false) => Some(format!(
+                "{:?} at neither position {} nor {}",
+                c, policy.first, policy.second
+            )),
+            _ => None,
+        })
+    }
+}
+
+/// The policy's letter is at both of the 1-based positions `first` and
+/// `second`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PositionAnd;
+
+impl Rule for PositionAnd {
+    fn violation(
+        &self,
+        policy: &PasswordPolicy,
+        password: &str,
+    ) -> Result<Option<String>, PolicyError> {
+        let mut missing = Vec::new();
+        for position in [policy.first, policy.second] {
+            if !policy.is_at(password, position)? {
+                missing.push(position.to_string());
+            }
+        }
+        Ok((!missing.is_empty()).then(|| {
+            format!(
+                "{:?} not at position {}",
+                policy.char,
+                missing.join(" or ")
+            )
+        }))
+    }
+}
+
+/// None of these characters occur in the password.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Forbidden(pub Vec<char>);
+
+impl Rule for Forbidden {
+    fn violation(&self, _: &PasswordPolicy, password: &str) -> Result<Option<String>, PolicyError> {
+        Ok(password
+            .chars()
+            .find(|c| self.0.contains(c))
+            .map(|c| format!("contains forbidden {:?}", c)))
+    }
+}
+
+/// The password is at least this many characters long, as counted by the
+/// policy's segmentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinLength(pub usize);
+
+impl Rule for MinLength {
+    fn violation(
+        &self,
+        policy: &PasswordPolicy,
+        password: &str,
+    ) -> Result<Option<String>, PolicyError> {
+        let len = policy.split(password).len();
+        Ok((len < self.0)
+            .then(|| format!("{} characters long, need at least {}", len, self.0)))
+    }
+}
+
+/// Every one of the rules passes.
+#[derive(Debug)]
+pub struct All(pub Vec<Box<dyn Rule>>);
+
+impl Rule for All {
+    fn violation(
+        &self,
+        policy: &PasswordPolicy,
+        password: &str,
+    ) -> Result<Option<String>, PolicyError> {
+        let mut violations = Vec::new();
+        for rule in &self.0 {
+            violations.extend(rule.violation(policy, password)?);
+        }
+        Ok((!violations.is_empty()).then(|| violations.join("; ")))
+    }
+}
+
+/// At least one of the rules passes.
+#[derive(Debug)]
+pub struct Any(pub Vec<Box<dyn Rule>>);
+
+impl Rule for Any {
+    fn violation(
+        &self,
+        policy: &PasswordPolicy,
+        password: &str,
+    ) -> Result<Option<String>, PolicyError> {
+        // every rule is checked before giving up on an error, so that the
+        // order of the rules does not matter
+        let results: Vec<_> = self
+            .0
+            .iter()
+            .map(|rule| rule.violation(policy, password))
+            .collect();
+        if results.iter().any(|r| matches!(r, Ok(None))) {
+            return Ok(None);
+        }
+        let mut violations = Vec::new();
+        for result in results {
+            violations.extend(result?);
+        }
+        Ok(Some(format!("none passed: {}", violations.join("; "))))
+    }
+}
+
+/// The rule fails.
+#[derive(Debug)]
+pub struct Not(pub Box<dyn Rule>);
+
+impl Rule for Not {
+    fn violation(
+        &self,
+        policy: &PasswordPolicy,
+        password: &str,
+    ) -> Result<Option<String>, PolicyError> {
+        Ok(match self.0.violation(policy, password)? {
+            Some(_) => None,
+            None => Some(format!("passes {:?}, which it must not", self.0)),
+        })
+    }
+}
+
+/// How a rule is written in a config file: a bare name for the rules without
+/// settings, such as `"count_range"`, and a one-entry table for the others,
+/// such as `{ min_length = 8 }` or `{ not = { forbidden = "0123456789" } }`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleConfig {
+    CountRange,
+    PositionXor,
+    PositionAnd,
+    Forbidden(String),
+    MinLength(usize),
+    All(Vec<RuleConfig>),
+    Any(Vec<RuleConfig>),
+    Not(Box<RuleConfig>),
+}
+
+impl RuleConfig {
+    pub fn build(self) -> Box<dyn Rule> {
+        match self {
+            Self::CountRange => Box::new(CountRange),
+            Self::PositionXor => Box::new(PositionXor),
+            Self::PositionAnd => Box::new(PositionAnd),
+            Self::Forbidden(chars) => Box::new(Forbidden(chars.chars().collect())),
+            Self::MinLength(len) => Box::new(MinLength(len)),
+            Self::All(rules) => Box::new(All(rules.into_iter().map(Self::build).collect())),
+            Self::Any(rules) => Box::new(Any(rules.into_iter().map(Self::build).collect())),
+            Self::Not(rule) => Box::new(Not(rule.build())),
+        }
+    }
+}
+
+/// A named rule set to audit a password database against.
+#[derive(Debug)]
+pub struct Policy {
+    pub name: String,
+    pub rule: Box<dyn Rule>,
+}
+
+/// How the entries of a database fared under one policy.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Audit {
+    pub valid: usize,
+    pub invalid: usize,
+    /// The entries that could not be checked, which count as neither.
+    pub errors: Vec<BadLine>,
+}
+
+impl Policy {
+    /// Part 1's interpretation: the numbers are a count range.
+    pub fn sled_rental() -> Self {
+        Self {
+            name: "sled rental".into(),
+            rule: Box::new(CountRange),
+        }
+    }
+
+    /// Part 2's interpretation: the numbers are a pair of positions.
+    pub fn toboggan() -> Self {
+        Self {
+            name: "toboggan".into(),
+            rule: Box::new(PositionXor),
+        }
+    }
+
+    /// Both interpretations from the puzzle.
+    pub fn puzzle() -> Vec<Self> {
+        vec![Self::sled_rental(), Self::toboggan()]
+    }
+
+    /// Checks every entry of `db`, carrying on past the ones that cannot be
+    /// checked.
+    pub fn audit(&self, db: &Database<'_>) -> Audit {
+        let mut res = Audit::default();
+        for entry in &db.entries {
+            match self.rule.check(&entry.policy, entry.password) {
+                Ok(true) => res.valid += 1,
+                Ok(false) => res.invalid += 1,
+                Err(error) => res.errors.push(BadLine {
+                    line: entry.line,
+                    error,
+                }),
+            }
+        }
+        res
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PolicyConfig {
+    name: String,
+    rule: RuleConfig,
+}
+
+#[derive(Debug, Deserialize)]
+struct PoliciesFile {
+    #[serde(default)]
+    policy: Vec<PolicyConfig>,
+}
+
+/// Parses the `[[policy]]` tables of a config file, each with a `name` and
+/// a `rule`.
+pub fn parse_policies(text: &str) -> Result<Vec<Policy>> {
+    let file: PoliciesFile = toml::from_str(text)?;
+    Ok(file
+        .policy
+        .into_iter()
+        .map(|p| Policy {
+            name: p.name,
+            rule: p.rule.build(),
+        })
+        .collect())
+}
+
+pub fn load_policies(path: &Path) -> Result<Vec<Policy>> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("could not read policies from {}", path.display()))?;
+    parse_policies(&text).with_context(|| format!("could not parse {}", path.display()))
+}
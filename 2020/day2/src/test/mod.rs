@@ -1,27 +1,250 @@
 use super::*;
+use aoc2020_common::solve;
 
 #[test]
 fn test_is_valid() {
     let policy = PasswordPolicy {
-        range: 1..=3,
-        char: b'a',
-        positions: (1, 3)
+        char: 'a',
+        first: 1,
+        second: 3,
+        segmentation: Segmentation::Chars,
     };
-    assert_eq!(policy.is_valid("zeus"), false, "no 'a's");
-    assert_eq!(policy.is_valid("hades"), true, "single 'a'");
-    assert_eq!(policy.is_valid("banana"), true, "three 'a's");
-    assert_eq!(policy.is_valid("aaaah"), false, "too many 'a's");    
+    assert!(!policy.is_valid("zeus"), "no 'a's");
+    assert!(policy.is_valid("hades"), "single 'a'");
+    assert!(policy.is_valid("banana"), "three 'a's");
+    assert!(!policy.is_valid("aaaah"), "too many 'a's");    
 }
 
 #[test]
 fn test_is_valid_in_position() {
     let policy = PasswordPolicy {
-        range: 1..=3,
-        char: b'a',
-        positions: (1, 3)
+        char: 'a',
+        first: 1,
+        second: 3,
+        segmentation: Segmentation::Chars,
     };
-    assert_eq!(policy.is_valid_in_position("abada"), false, "'a' in both positions");
-    assert_eq!(policy.is_valid_in_position("keanu"), true, "'a' in second position");
-    assert_eq!(policy.is_valid_in_position("apple"), true, "'a' in first position");
-    assert_eq!(policy.is_valid_in_position("password"), false, "no 'a' at all");
-}
\ No newline at end of file
+    assert!(!policy.is_valid_in_position("abada").unwrap(), "'a' in both positions");
+    assert!(policy.is_valid_in_position("keanu").unwrap(), "'a' in second position");
+    assert!(policy.is_valid_in_position("apple").unwrap(), "'a' in first position");
+    assert!(!policy.is_valid_in_position("password").unwrap(), "no 'a' at all");
+}
+
+#[test]
+fn test_example() {
+    let [part1, part2] = solve::<Day2>(include_str!("../../fixtures/example.txt")).unwrap();
+    assert_eq!(part1.unwrap(), Answer::Number(2));
+    assert_eq!(part2.unwrap(), Answer::Number(1));
+}
+
+#[test]
+fn test_rules() {
+    use rules::*;
+
+    let policy = PasswordPolicy {
+        char: 'a',
+        first: 1,
+        second: 3,
+        segmentation: Segmentation::Chars,
+    };
+    assert_eq!(PositionAnd.check(&policy, "abada"), Ok(true));
+    assert_eq!(PositionAnd.check(&policy, "keanu"), Ok(false));
+    assert_eq!(Forbidden("0123456789".chars().collect()).check(&policy, "banana"), Ok(true));
+    assert_eq!(Forbidden("0123456789".chars().collect()).check(&policy, "banana9"), Ok(false));
+    assert_eq!(MinLength(5).check(&policy, "hades"), Ok(true));
+    assert_eq!(MinLength(6).check(&policy, "hades"), Ok(false));
+
+    let rule = All(vec![Box::new(CountRange), Box::new(Not(Box::new(PositionAnd)))]);
+    assert_eq!(rule.check(&policy, "banana"), Ok(true));
+    assert_eq!(rule.check(&policy, "abada"), Ok(false), "'a' in both positions");
+    assert_eq!(rule.check(&policy, "zeus"), Ok(false), "no 'a's");
+    let rule = Any(vec![Box::new(MinLength(6)), Box::new(PositionXor)]);
+    assert_eq!(rule.check(&policy, "zeus-zeus"), Ok(true));
+    assert_eq!(rule.check(&policy, "apple"), Ok(true));
+    assert_eq!(rule.check(&policy, "zeus"), Ok(false));
+}
+
+#[test]
+fn test_any_ignores_order() {
+    use rules::*;
+
+    let policy = PasswordPolicy {
+        char: 'a',
+        first: 1,
+        second: 9,
+        segmentation: Segmentation::Chars,
+    };
+    let forward = Any(vec![Box::new(MinLength(1)), Box::new(PositionXor)]);
+    let backward = Any(vec![Box::new(PositionXor), Box::new(MinLength(1))]);
+    assert_eq!(forward.check(&policy, "abc"), Ok(true));
+    assert_eq!(backward.check(&policy, "abc"), Ok(true));
+
+    // with nothing passing, the error wins either way
+    let forward = Any(vec![Box::new(MinLength(5)), Box::new(PositionXor)]);
+    let backward = Any(vec![Box::new(PositionXor), Box::new(MinLength(5))]);
+    let error = Err(PolicyError::BadPosition { position: 9, len: 3 });
+    assert_eq!(forward.check(&policy, "abc"), error);
+    assert_eq!(backward.check(&policy, "abc"), error);
+}
+
+#[test]
+fn test_policies() {
+    let policies = rules::parse_policies(
+        r#"
+        [[policy]]
+        name = "sled"
+        rule = "count_range"
+
+        [[policy]]
+        name = "long toboggan"
+        rule = { all = ["position_xor", { not = { min_length = 10 } }] }
+        "#,
+    )
+    .unwrap();
+    let db = Day2::parse(include_str!("../../fixtures/example.txt")).unwrap();
+    let counts: Vec<_> = policies
+        .iter()
+        .map(|p| (p.name.as_str(), p.audit(&db).valid))
+        .collect();
+    assert_eq!(counts, vec![("sled", 2), ("long toboggan", 1)]);
+
+    assert!(rules::parse_policies("[[policy]]\nname = \"x\"\nrule = \"shortest\"").is_err());
+}
+
+#[test]
+fn test_violations() {
+    use rules::*;
+
+    let policy = PasswordPolicy {
+        char: 'a',
+        first: 1,
+        second: 3,
+        segmentation: Segmentation::Chars,
+    };
+    assert_eq!(
+        CountRange.violation(&policy, "aaaah").unwrap().unwrap(),
+        "found 4 'a', allowed 1..=3"
+    );
+    assert_eq!(
+        PositionXor.violation(&policy, "abada").unwrap().unwrap(),
+        "'a' at both positions 1 and 3"
+    );
+    assert_eq!(
+        PositionXor.violation(&policy, "zeus").unwrap().unwrap(),
+        "'a' at neither position 1 nor 3"
+    );
+    assert_eq!(
+        PositionAnd.violation(&policy, "apple").unwrap().unwrap(),
+        "'a' not at position 3"
+    );
+    assert_eq!(
+        Not(Box::new(CountRange))
+            .violation(&policy, "banana")
+            .unwrap()
+            .unwrap(),
+        "passes CountRange, which it must not"
+    );
+    assert_eq!(CountRange.violation(&policy, "banana"), Ok(None));
+}
+
+#[test]
+fn test_report() {
+    let db = Day2::parse(include_str!("../../fixtures/example.txt")).unwrap();
+    let policies = rules::Policy::puzzle();
+    let reports = report::report(&db, &policies);
+
+    let mut table = Vec::new();
+    report::write_table(&reports, &policies, &mut table).unwrap();
+    assert_eq!(
+        String::from_utf8(table).unwrap(),
+        "\
+line  policy  password   sled rental                 toboggan
+1     1-3 a   abcde      ok                          ok
+2     1-3 b   cdefg      found 0 'b', allowed 1..=3  'b' at neither position 1 nor 3
+3     2-9 c   ccccccccc  ok                          'c' at both positions 2 and 9
+"
+    );
+
+    let mut json = Vec::new();
+    report::write_json_lines(&reports[1..2], &mut json).unwrap();
+    assert_eq!(
+        String::from_utf8(json).unwrap(),
+        r#"{"line":2,"policy":{"char":"b","first":1,"second":3},"password":"cdefg","verdicts":[{"policy":"sled rental","valid":false,"reason":"found 0 'b', allowed 1..=3"},{"policy":"toboggan","valid":false,"reason":"'b' at neither position 1 nor 3"}]}"#
+            .to_owned()
+            + "\n"
+    );
+}
+
+#[test]
+fn test_bad_positions() {
+    let policy = PasswordPolicy {
+        char: 'a',
+        first: 0,
+        second: 3,
+        segmentation: Segmentation::Chars,
+    };
+    assert_eq!(
+        policy.is_valid_in_position("abc"),
+        Err(PolicyError::BadPosition {
+            position: 0,
+            len: 3
+        })
+    );
+    let policy = PasswordPolicy { first: 1, ..policy };
+    assert_eq!(policy.is_valid_in_position("abc"), Ok(true));
+    assert_eq!(
+        policy.is_valid_in_position("ab"),
+        Err(PolicyError::BadPosition {
+            position: 3,
+            len: 2
+        })
+    );
+}
+
+#[test]
+fn test_bad_lines() {
+    let input = "1-3 a: abcde\n1-3 b cdefg\n2-9 c: ccccccccc\n1-2 é: aé\n4-5 d: dd\n";
+    let db = Day2::parse(input).unwrap();
+    assert_eq!(db.entries.len(), 4);
+    let bad: Vec<_> = db.bad_lines.iter().map(|b| (b.line, &b.error)).collect();
+    assert!(matches!(bad[..], [(2, PolicyError::Malformed(_))]));
+
+    let overflow = Day2::parse("99999999999999999999999-1 a: x\n1-3 a: abcde\n").unwrap();
+    assert_eq!(overflow.entries.len(), 1);
+    assert!(matches!(
+        overflow.bad_lines[..],
+        [BadLine { line: 1, error: PolicyError::Malformed(_) }]
+    ));
+
+    let [part1, part2] = solve::<Day2>(input).unwrap();
+    assert_eq!(part1.unwrap(), Answer::Number(3));
+    assert_eq!(part2.unwrap(), Answer::Number(2));
+    let warnings = Day2::warnings(&db);
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings[0].starts_with("line 2: malformed line: "));
+    assert_eq!(
+        warnings[1],
+        "line 5: position 4 is outside the 2-character password (toboggan)"
+    );
+}
+
+#[test]
+fn test_unicode() {
+    let (policy, password) = parse_line("1-4 é: éte\u{301}é").unwrap();
+    assert_eq!(policy.char, 'é');
+    assert_eq!(policy.count(password), 2);
+    assert_eq!(policy.split(password).len(), 5);
+    assert_eq!(policy.is_valid_in_position(password), Ok(true));
+
+    let policy = PasswordPolicy {
+        segmentation: Segmentation::Graphemes,
+        ..policy
+    };
+    assert_eq!(policy.split(password), vec!["é", "t", "e\u{301}", "é"]);
+    assert_eq!(policy.count(password), 2);
+    assert_eq!(policy.is_valid_in_position(password), Ok(false), "'é' at 1 and 4");
+    assert_eq!(rules::MinLength(5).check(&policy, password), Ok(false));
+
+    let db = Database::parse_with("1-2 ß: straße\n", Segmentation::Graphemes);
+    assert_eq!(db.entries[0].policy.segmentation, Segmentation::Graphemes);
+    assert_eq!(rules::Policy::sled_rental().audit(&db).valid, 1);
+}
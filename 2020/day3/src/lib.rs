@@ -0,0 +1,170 @@
+use aoc2020_common::grid::{Boundary, Grid, GridError};
+use aoc2020_common::{Answer, Solution, Vec2};
+use anyhow::Result;
+use std::fmt::Debug;
+
+pub mod render;
+pub mod route;
+pub mod slopes;
+pub mod stacked;
+
+#[cfg(test)]
+mod test;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Parsed<'a> = RoadMap;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(RoadMap::parse(input)?)
+    }
+
+    fn part1(map: &RoadMap) -> Result<Answer> {
+        Ok(count_trees(map, (3, 1).into()).into())
+    }
+
+    fn part2(map: &RoadMap) -> Result<Answer> {
+        let deltas: &[Vec2<i64>] = &[
+            (1, 1).into(),
+            (3, 1).into(),
+            (5, 1).into(),
+            (7, 1).into(),
+            (1, 2).into(),
+        ];
+        let answer = map
+            // count trees along every itinerary
+            .count_trees_many(deltas)
+            .into_iter()
+            // multiply everything together
+            .product::<usize>();
+        Ok(answer.into())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum Tile {
+    #[default]
+    Open,
+    Tree
+}
+
+impl Debug for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Open => write!(f, "."),
+            Self::Tree => write!(f, "#"),
+        }
+    }
+}
+
+impl Tile {
+    fn parse(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Open),
+            '#' => Some(Self::Tree),
+            _ => None,
+        }
+    }
+}
+
+/// The slope, which repeats endlessly to the right unless given another
+/// boundary.
+#[derive(Clone, PartialEq)]
+pub struct RoadMap {
+    grid: Grid<Tile>,
+    step_limit: Option<usize>,
+}
+
+impl RoadMap {
+    pub fn size(&self) -> Vec2<i64> {
+        self.grid.size()
+    }
+
+    pub fn new(size: Vec2<i64>) -> Self {
+        Self {
+            grid: Grid::new(size).with_boundary(Boundary::WrapX),
+            step_limit: None,
+        }
+    }
+
+    pub fn set(&mut self, pos: Vec2<i64>, tile: Tile) {
+        self.grid.set(pos, tile)
+    }
+
+    pub fn get(&self, pos: Vec2<i64>) -> Tile {
+        self.grid.get(pos).copied().unwrap_or_default()
+    }
+
+    pub fn parse(input: &str) -> Result<Self, GridError> {
+        Ok(Self {
+            grid: Grid::parse(input, Tile::parse)?.with_boundary(Boundary::WrapX),
+            step_limit: None,
+        })
+    }
+
+    /// Sets what happens past the edges of the map; see `Boundary`.
+    pub fn with_boundary(self, boundary: Boundary) -> Self {
+        Self {
+            grid: self.grid.with_boundary(boundary),
+            ..self
+        }
+    }
+
+    pub fn boundary(&self) -> Boundary {
+        self.grid.boundary()
+    }
+
+    /// Caps the number of positions on an itinerary, for boundaries where
+    /// it would otherwise never end.
+    pub fn with_step_limit(self, limit: usize) -> Self {
+        Self {
+            step_limit: Some(limit),
+            ..self
+        }
+    }
+
+    /// The most positions an itinerary visits. Unless set, this is enough
+    /// for any path to get back to where it started, on every boundary.
+    pub fn step_limit(&self) -> usize {
+        let Vec2 { x: w, y: h } = self.size();
+        self.step_limit
+            .unwrap_or_else(|| (4 * w.max(0) * h.max(0)) as usize)
+    }
+
+    pub fn index(&self, pos: Vec2<i64>) -> Option<usize> {
+        self.grid.index(pos)
+    }
+
+    pub fn normalize_pos(&self, pos: Vec2<i64>) -> Option<Vec2<i64>> {
+        self.grid.normalize_pos(pos)
+    }
+}
+
+impl Debug for RoadMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.grid)
+    }
+}
+
+/// The positions visited from the top left corner, moving by `delta` until
+/// leaving the map or reaching its step limit.
+///
+/// The positions are not normalized, so a path that wraps keeps counting
+/// up past the edge.
+pub fn itinerary(map: &RoadMap, delta: Vec2<i64>) -> impl Iterator<Item = Vec2<i64>> + '_ {
+    std::iter::successors(Some(Vec2::default()), move |&pos| Some(pos + delta))
+        .take_while(|&pos| map.normalize_pos(pos).is_some())
+        .take(map.step_limit())
+}
+
+pub fn generate_itinerary(map: &RoadMap, delta: Vec2<i64>) -> Vec<Vec2<i64>> {
+    itinerary(map, delta).collect()
+}
+
+pub fn count_trees(map: &RoadMap, delta: Vec2<i64>) -> usize {
+    itinerary(map, delta)
+        .filter(|&pos| map.get(pos) == Tile::Tree)
+        .count()
+}
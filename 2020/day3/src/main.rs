@@ -1,161 +1,79 @@
-use std::fmt::Debug;
-
-#[cfg(test)]
-mod test;
-
-fn main() {
-    let map = RoadMap::parse(include_bytes!("input.txt"));
-
-    let deltas: &[Pair] = &[
-        (1, 1).into(),
-        (3, 1).into(),
-        (5, 1).into(),
-        (7, 1).into(),
-        (1, 2).into(),
-    ];
-    let answer = deltas
-        .iter()
-        .copied()
-        // generate all itineraries
-        .map(|delta| generate_itinerary(&map, delta))
-        // count trees
-        .map(|itin| {
-            itin.into_iter()
-                .filter(|&pos| map.get(pos) == Tile::Tree)
-                .count()
-        })
-        // multiply everything together
-        .product::<usize>();
-
-    println!("The answer is {}", answer);
-}
-
-#[derive(Clone, Copy, Debug, PartialEq)]
-struct Pair {
-    x: i64,
-    y: i64,
-}
-
-impl From<(i64, i64)> for Pair {
-    fn from((x, y): (i64, i64)) -> Self {
-        Self { x, y }
-    }
-}
-
-#[derive(Clone, Copy, PartialEq)]
-enum Tile {
-    Open,
-    Tree
-}
-
-impl Default for Tile {
-    fn default() -> Self {
-        Tile::Open
-    }
-}
-
-impl Debug for Tile {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Open => write!(f, "."),
-            Self::Tree => write!(f, "#"),
-        }
-    }
+use aoc2020_common::input::Source;
+use aoc2020_common::{run_with, take_flag, take_option, Args, Boundary, Solution, Vec2};
+use anyhow::{anyhow, bail, Context};
+use day3::render::Style;
+use day3::route::{TileCosts, DOWNHILL};
+use day3::{generate_itinerary, Day3};
+
+/// Parses a slope written as `dx,dy`.
+fn parse_slope(s: &str) -> anyhow::Result<Vec2<i64>> {
+    let (dx, dy) = s
+        .split_once(',')
+        .ok_or_else(|| anyhow!("expected a slope such as 3,1, got {:?}", s))?;
+    let parse = |n: &str| n.trim().parse().with_context(|| format!("invalid slope {:?}", s));
+    Ok(Vec2::new(parse(dx)?, parse(dy)?))
 }
 
-struct RoadMap {
-    size: Pair,
-    tiles: Vec<Tile>,
+fn parse_boundary(s: &str) -> anyhow::Result<Boundary> {
+    Ok(match s {
+        "bounded" => Boundary::Bounded,
+        "wrap-x" => Boundary::WrapX,
+        "wrap-y" => Boundary::WrapY,
+        "torus" => Boundary::Torus,
+        "mirror" => Boundary::Mirror,
+        _ => bail!("--boundary must be bounded, wrap-x, wrap-y, torus or mirror, got {:?}", s),
+    })
 }
 
-impl RoadMap {
-    fn new(size: Pair) -> Self {
-        let num_tiles = size.x * size.y;
-        Self {
-            size,
-            tiles: (0..num_tiles)
-            .into_iter()
-            .map(|_| Default::default())
-            .collect()
-        }
-    }
-
-    fn set(&mut self, pos: Pair, tile: Tile) {
-        if let Some(index) = self.index(pos) {
-            self.tiles[index] = tile;
-        }
-    }
-
-    fn get(&self, pos: Pair) -> Tile {
-        self.index(pos).map(|i| self.tiles[i]).unwrap_or_default()
-    }
-
-    fn size(input: &[u8]) -> Pair {
-        let mut columns = 0;
-        let mut rows = 1;
-        for &c in input.iter() {
-            if c == b'\n' {
-                rows += 1;
-                columns = 0;
-            } else {
-                columns += 1;
-            }
-        }
-        (columns, rows).into()        
+/// Solves the puzzle, or with `--render <plain|ansi>` draws the map with the
+/// itinerary of every `--slope <dx,dy>` on it, by default part 1's 3,1.
+/// `--boundary` picks what happens past the edges, by default `wrap-x`.
+/// `--route` draws the route down with the fewest trees instead.
+fn main() -> anyhow::Result<()> {
+    let mut args: Vec<_> = std::env::args_os().skip(1).collect();
+    let style = take_option(&mut args, "--render")?;
+    let mut slopes = Vec::new();
+    while let Some(slope) = take_option(&mut args, "--slope")? {
+        slopes.push(parse_slope(&slope.to_string_lossy())?);
     }
-
-    fn parse(input: &[u8]) -> Self {
-        let mut iter = input.iter().copied();
-        let mut map = Self::new(Self::size(input));
-        for row in 0..map.size.y {
-            for col in 0..map.size.x {
-                let tile = match iter.next() {
-                    Some(b'.') => Tile::Open,
-                    Some(b'#') => Tile::Tree,
-                    c => panic!("Expected '.' or '#', but got: {:?}", c),
-                };
-                map.set((col, row).into(), tile);
-            }
-            iter.next();
+    let boundary = take_option(&mut args, "--boundary")?
+        .map(|b| parse_boundary(&b.to_string_lossy()))
+        .transpose()?;
+    let route = take_flag(&mut args, "--route");
+    let args = Args::parse(args)?;
+    let style = match style.as_ref().map(|s| s.to_str()) {
+        None if slopes.is_empty() && boundary.is_none() && !route => {
+            return run_with::<Day3>(args)
         }
-        map
+        None => bail!("--slope, --boundary and --route only apply to --render"),
+        Some(Some("plain")) => Style::Plain,
+        Some(Some("ansi")) => Style::Ansi,
+        Some(_) => bail!("--render must be plain or ansi, got {:?}", style.unwrap()),
+    };
+    if args.part.is_some() {
+        bail!("--part cannot be combined with --render");
     }
-
-    fn index(&self, pos: Pair) -> Option<usize> {
-        self.normalize_pos(pos).map(|val| (val.x + val.y * self.size.x) as _)
+    if route && !slopes.is_empty() {
+        bail!("--route cannot be combined with --slope");
     }
-
-    fn normalize_pos(&self, pos: Pair) -> Option<Pair> {
-        if pos.y < 0 || pos.y >= self.size.y {
-            None
-        } else {
-            let x = pos.x % self.size.x;
-            let x = if x < 0 { self.size.x + x } else { x };
-            Some((x, pos.y).into())
-        }
+    if slopes.is_empty() {
+        slopes.push(Vec2::new(3, 1));
     }
-}
 
-impl Debug for RoadMap {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in 0..self.size.y {
-            for col in 0..self.size.x {
-                write!(f, "{:?}", self.get((row, col).into()))?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+    let map = Day3::parse(&Source::resolve(Day3::DAY, args.input).read()?)?
+        .with_boundary(boundary.unwrap_or(Boundary::WrapX));
+    if route {
+        let route = map
+            .cheapest_route(&DOWNHILL, TileCosts::default())
+            .ok_or_else(|| anyhow!("there is no way down"))?;
+        print!("{}", map.render(&[route.path], style));
+        println!("{} tree(s) on the way", route.cost);
+        return Ok(());
     }
+    let paths: Vec<_> = slopes
+        .into_iter()
+        .map(|slope| generate_itinerary(&map, slope))
+        .collect();
+    print!("{}", map.render(&paths, style));
+    Ok(())
 }
-
-fn generate_itinerary(map: &RoadMap, delta: Pair) -> Vec<Pair> {
-    let mut pos = Pair::from((0, 0));
-    let mut res: Vec<_> = Default::default();
-
-    while map.normalize_pos(pos).is_some() {
-        res.push(pos);
-        pos.x += delta.x;
-        pos.y += delta.y;
-    }
-    res
-}
\ No newline at end of file
@@ -0,0 +1,71 @@
+//! Drawing itineraries over the map, as in the puzzle text.
+
+use crate::{RoadMap, Tile};
+use aoc2020_common::{Boundary, Vec2};
+use std::collections::HashSet;
+
+/// How `RoadMap::render` draws.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Style {
+    /// Plain ASCII.
+    #[default]
+    Plain,
+    /// ASCII with ANSI escape codes colouring the paths and the trees.
+    Ansi,
+}
+
+const RESET: &str = "\x1b[0m";
+const HIT: &str = "\x1b[1;31m";
+const MISS: &str = "\x1b[1;32m";
+const TREE: &str = "\x1b[2;32m";
+
+impl RoadMap {
+    /// Draws the map with every position on `paths` marked: `X` for a tree
+    /// and `O` for an open square.
+    ///
+    /// On a map that only wraps sideways, the map is repeated as many whole
+    /// times as it takes to show every position, and positions above or
+    /// below it are left out. On other boundaries, every position is drawn
+    /// where it lands on the map itself.
+    pub fn render(&self, paths: &[Vec<Vec2<i64>>], style: Style) -> String {
+        let Vec2 { x: w, y: h } = self.size();
+        if w <= 0 || h <= 0 {
+            return String::new();
+        }
+        let marked: HashSet<_> = paths
+            .iter()
+            .flatten()
+            .filter_map(|&pos| match self.boundary() {
+                Boundary::WrapX => Some(pos),
+                _ => self.normalize_pos(pos),
+            })
+            .filter(|pos| (0..h).contains(&pos.y))
+            .collect();
+        // whole copies of the map, including the first one
+        let left = marked.iter().map(|pos| pos.x.div_euclid(w)).min().unwrap_or(0).min(0);
+        let right = marked.iter().map(|pos| pos.x.div_euclid(w)).max().unwrap_or(0).max(0);
+
+        let mut res = String::new();
+        for y in 0..h {
+            for x in left * w..(right + 1) * w {
+                let pos = Vec2::new(x, y);
+                let (c, color) = match (self.get(pos), marked.contains(&pos)) {
+                    (Tile::Tree, true) => ('X', HIT),
+                    (Tile::Open, true) => ('O', MISS),
+                    (Tile::Tree, false) => ('#', TREE),
+                    (Tile::Open, false) => ('.', ""),
+                };
+                match style {
+                    Style::Ansi if !color.is_empty() => {
+                        res.push_str(color);
+                        res.push(c);
+                        res.push_str(RESET);
+                    }
+                    _ => res.push(c),
+                }
+            }
+            res.push('\n');
+        }
+        res
+    }
+}
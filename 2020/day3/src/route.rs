@@ -0,0 +1,130 @@
+//! Finding the cheapest way down the slope, rather than a straight line.
+
+use crate::{RoadMap, Tile};
+use aoc2020_common::{Boundary, Vec2};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// What entering a tile of each type costs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TileCosts {
+    pub open: u64,
+    pub tree: u64,
+}
+
+impl Default for TileCosts {
+    /// Each tree costs one, so the cheapest route hits the fewest trees.
+    fn default() -> Self {
+        Self { open: 0, tree: 1 }
+    }
+}
+
+impl TileCosts {
+    pub fn cost(&self, tile: Tile) -> u64 {
+        match tile {
+            Tile::Open => self.open,
+            Tile::Tree => self.tree,
+        }
+    }
+}
+
+/// A way from the top row to the bottom row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route {
+    /// Every position on the way. On a map that only wraps sideways these
+    /// are not normalized, so that a route which wraps keeps going past the
+    /// edge, as with `itinerary`. On other boundaries they are the tiles
+    /// themselves.
+    pub path: Vec<Vec2<i64>>,
+    /// The cost of every tile on the way, the first and last included.
+    pub cost: u64,
+}
+
+/// Moving straight down, or diagonally down to either side.
+pub const DOWNHILL: [Vec2<i64>; 3] = [
+    Vec2 { x: 0, y: 1 },
+    Vec2 { x: -1, y: 1 },
+    Vec2 { x: 1, y: 1 },
+];
+
+impl RoadMap {
+    /// Finds the cheapest route starting anywhere on the top row and ending
+    /// anywhere on the bottom row, taking only the given `moves` and
+    /// following the map's boundary. Among routes that cost the same, the
+    /// one found first wins.
+    ///
+    /// Every move is taken from the tile the route is on, so on a mirrored
+    /// map a move off an edge lands on the tile it bounces back to.
+    ///
+    /// Returns `None` if the moves cannot reach the bottom row.
+    pub fn cheapest_route(&self, moves: &[Vec2<i64>], costs: TileCosts) -> Option<Route> {
+        let Vec2 { x: w, y: h } = self.size();
+        if w <= 0 || h <= 0 {
+            return None;
+        }
+        let index = |pos: Vec2<i64>| (pos.x + pos.y * w) as usize;
+        // best cost so far to every tile, and the tile and move it came from
+        let mut best = vec![u64::MAX; (w * h) as usize];
+        let mut came_from: Vec<Option<(Vec2<i64>, Vec2<i64>)>> = vec![None; best.len()];
+        let mut queue = BinaryHeap::new();
+        for x in 0..w {
+            let pos = Vec2::new(x, 0);
+            best[index(pos)] = costs.cost(self.get(pos));
+            queue.push(Reverse((best[index(pos)], pos.y, pos.x)));
+        }
+
+        while let Some(Reverse((cost, y, x))) = queue.pop() {
+            let pos = Vec2::new(x, y);
+            if cost > best[index(pos)] {
+                continue;
+            }
+            if y == h - 1 {
+                return Some(Route {
+                    path: self.unwind(pos, &came_from),
+                    cost,
+                });
+            }
+            for &delta in moves {
+                let Some(next) = self.normalize_pos(pos + delta) else {
+                    continue;
+                };
+                let next_cost = cost + costs.cost(self.get(next));
+                if next_cost < best[index(next)] {
+                    best[index(next)] = next_cost;
+                    came_from[index(next)] = Some((pos, delta));
+                    queue.push(Reverse((next_cost, next.y, next.x)));
+                }
+            }
+        }
+        None
+    }
+
+    /// Walks back from `end` to the top row. On maps that only wrap
+    /// sideways, the moves are then replayed from there without normalizing,
+    /// since normalizing only shifts positions by whole copies of the map.
+    fn unwind(
+        &self,
+        end: Vec2<i64>,
+        came_from: &[Option<(Vec2<i64>, Vec2<i64>)>],
+    ) -> Vec<Vec2<i64>> {
+        let w = self.size().x;
+        let mut tiles = vec![end];
+        let mut moves = Vec::new();
+        let mut pos = end;
+        while let Some((prev, delta)) = came_from[(pos.x + pos.y * w) as usize] {
+            tiles.push(prev);
+            moves.push(delta);
+            pos = prev;
+        }
+        if !matches!(self.boundary(), Boundary::Bounded | Boundary::WrapX) {
+            tiles.reverse();
+            return tiles;
+        }
+        let mut path = vec![pos];
+        for delta in moves.into_iter().rev() {
+            pos += delta;
+            path.push(pos);
+        }
+        path
+    }
+}
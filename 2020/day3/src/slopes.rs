@@ -0,0 +1,167 @@
+//! Comparing many slopes down the same map.
+
+use crate::{count_trees, RoadMap, Tile};
+use aoc2020_common::{Boundary, Vec2};
+use bitvec::prelude::*;
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+/// The trees of a `RoadMap`, one bit per tile, so that even maps thousands
+/// of tiles wide and tall stay small.
+///
+/// Only the boundaries a path leaves through the bottom of are supported:
+/// `Boundary::WrapX` repeats the map sideways, anything else is bounded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackedMap {
+    width: i64,
+    wrap_x: bool,
+    /// As `RoadMap::step_limit`.
+    step_limit: usize,
+    rows: Vec<BitVec>,
+}
+
+impl From<&RoadMap> for PackedMap {
+    fn from(map: &RoadMap) -> Self {
+        Self {
+            width: map.size().x,
+            wrap_x: map.boundary() == Boundary::WrapX,
+            step_limit: map.step_limit(),
+            rows: map
+                .grid
+                .rows()
+                .map(|row| row.iter().map(|&tile| tile == Tile::Tree).collect())
+                .collect(),
+        }
+    }
+}
+
+impl PackedMap {
+    /// Maps `x` into the map, or returns `None` if it lies off the side.
+    fn column(&self, x: i64) -> Option<usize> {
+        match self.width > 0 {
+            true if self.wrap_x => Some(x.rem_euclid(self.width) as usize),
+            true if (0..self.width).contains(&x) => Some(x as usize),
+            _ => None,
+        }
+    }
+
+    /// Whether there is a tree at `pos`, repeating the map to the left and
+    /// right if it wraps.
+    pub fn is_tree(&self, pos: Vec2<i64>) -> bool {
+        let row = usize::try_from(pos.y).ok().and_then(|y| self.rows.get(y));
+        match (row, self.column(pos.x)) {
+            (Some(row), Some(x)) => row[x],
+            _ => false,
+        }
+    }
+
+    /// Counts the trees hit on each of the slopes, in one pass from the top
+    /// row to the bottom one.
+    ///
+    /// Slopes are grouped by `dy`, and each row only looks at the groups
+    /// that reach it, so a slope costs one step per row it visits. A slope
+    /// stops after the map's step limit, as with `itinerary`.
+    ///
+    /// # Panics
+    ///
+    /// If a slope has `dy == 0`, since it would never leave the map.
+    pub fn count_trees(&self, deltas: &[Vec2<i64>]) -> Vec<usize> {
+        assert!(
+            deltas.iter().all(|d| d.y != 0),
+            "a slope with dy = 0 never leaves the map"
+        );
+        let mut groups: BTreeMap<i64, Vec<usize>> = BTreeMap::new();
+        for (i, delta) in deltas.iter().enumerate() {
+            groups.entry(delta.y).or_default().push(i);
+        }
+        let groups: Vec<_> = groups.into_iter().collect();
+        // the groups that reach each row, filled in as the rows go by
+        let mut due = vec![Vec::new(); self.rows.len()];
+        if let Some(first) = due.first_mut() {
+            *first = (0..groups.len()).collect();
+        }
+
+        let mut counts = vec![0; deltas.len()];
+        for (y, row) in self.rows.iter().enumerate() {
+            for group in std::mem::take(&mut due[y]) {
+                let (dy, ref slopes) = groups[group];
+                // slopes going up only visit the first row
+                let step = y as i64 / dy.max(1);
+                if step as u64 >= self.step_limit as u64 {
+                    continue;
+                }
+                for &i in slopes {
+                    // a path that leaves through the side never comes back
+                    if let Some(x) = self.column(step * deltas[i].x) {
+                        counts[i] += usize::from(row[x]);
+                    }
+                }
+                let next = usize::try_from(dy).ok().and_then(|dy| y.checked_add(dy));
+                if let Some(next) = next.filter(|&next| next < self.rows.len()) {
+                    due[next].push(group);
+                }
+            }
+        }
+        counts
+    }
+}
+
+/// Slopes with the number of trees each one hits, fewest first, and by
+/// slope among equal counts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlopeRanking(pub Vec<(Vec2<i64>, usize)>);
+
+impl SlopeRanking {
+    /// The slopes tied for the fewest trees.
+    pub fn fewest(&self) -> &[(Vec2<i64>, usize)] {
+        let count = self.0.first().map(|&(_, count)| count);
+        let ties = self.0.iter().take_while(|&&(_, c)| Some(c) == count).count();
+        &self.0[..ties]
+    }
+
+    /// The slopes tied for the most trees.
+    pub fn most(&self) -> &[(Vec2<i64>, usize)] {
+        let count = self.0.last().map(|&(_, count)| count);
+        let ties = self.0.iter().rev().take_while(|&&(_, c)| Some(c) == count).count();
+        &self.0[self.0.len() - ties..]
+    }
+}
+
+impl RoadMap {
+    /// Counts the trees hit on each of the slopes, in a single pass if the
+    /// map is bounded or only wraps sideways (see `PackedMap::count_trees`),
+    /// and one slope at a time up to the step limit otherwise.
+    ///
+    /// Slopes that do not go down are also walked one at a time, as
+    /// `count_trees` does.
+    pub fn count_trees_many(&self, deltas: &[Vec2<i64>]) -> Vec<usize> {
+        if !matches!(self.boundary(), Boundary::Bounded | Boundary::WrapX) {
+            return deltas.iter().map(|&delta| count_trees(self, delta)).collect();
+        }
+        let downhill: Vec<_> = deltas.iter().copied().filter(|d| d.y > 0).collect();
+        let mut packed = PackedMap::from(self).count_trees(&downhill).into_iter();
+        deltas
+            .iter()
+            .map(|&delta| match delta.y > 0 {
+                true => packed.next().unwrap(),
+                false => count_trees(self, delta),
+            })
+            .collect()
+    }
+
+    /// Counts the trees on every slope with `dx` and `dy` in the given
+    /// ranges and ranks them.
+    ///
+    /// A `dy` below 1 never reaches the bottom of the map, so those slopes
+    /// are left out.
+    pub fn rank_slopes(&self, dx: RangeInclusive<i64>, dy: RangeInclusive<i64>) -> SlopeRanking {
+        let dy = (*dy.start()).max(1)..=*dy.end();
+        let deltas: Vec<_> = dy
+            .flat_map(|dy| dx.clone().map(move |dx| Vec2::new(dx, dy)))
+            .collect();
+        let counts = self.count_trees_many(&deltas);
+        let mut ranking: Vec<_> = deltas.into_iter().zip(counts).collect();
+        ranking.sort_unstable_by_key(|&(delta, count)| (count, delta.x, delta.y));
+        SlopeRanking(ranking)
+    }
+}
@@ -0,0 +1,62 @@
+//! Counting trees on the map stacked on top of itself, up to absurd heights.
+
+use crate::{RoadMap, Tile};
+use aoc2020_common::Vec2;
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+impl RoadMap {
+    /// The map with `copies` of itself stacked from top to bottom.
+    pub fn stack(&self, copies: usize) -> RoadMap {
+        let Vec2 { x: w, y: h } = self.size();
+        let mut res = RoadMap::new(Vec2::new(w, h * copies as i64)).with_boundary(self.boundary());
+        res.step_limit = self.step_limit;
+        for y in 0..h * copies as i64 {
+            for x in 0..w {
+                res.set(Vec2::new(x, y), self.get(Vec2::new(x, y % h)));
+            }
+        }
+        res
+    }
+
+    /// Counts the trees on the way down by `delta` from the top left corner
+    /// of the map stacked `copies` times, which repeats sideways whatever
+    /// the boundary of this one.
+    ///
+    /// Both coordinates repeat along the way, so the tiles visited form a
+    /// cycle at most as long as the map has tiles. Only that cycle is
+    /// walked, however tall the stack.
+    ///
+    /// # Panics
+    ///
+    /// If `delta.y == 0`, since the path would never leave the map.
+    pub fn count_trees_stacked(&self, delta: Vec2<i64>, copies: u64) -> u128 {
+        assert!(delta.y != 0, "a slope with dy = 0 never leaves the map");
+        let Vec2 { x: w, y: h } = self.size();
+        if w <= 0 || h <= 0 || copies == 0 {
+            return 0;
+        }
+        let tile_at = |k: i64| {
+            let pos = Vec2::new(k * delta.x.rem_euclid(w) % w, k * delta.y.rem_euclid(h) % h);
+            self.get(pos) == Tile::Tree
+        };
+        // slopes going up only visit the top left corner
+        if delta.y < 0 {
+            return u128::from(tile_at(0));
+        }
+
+        let steps = (u128::from(copies) * h as u128).div_ceil(delta.y as u128);
+        let period_x = w / gcd(delta.x.rem_euclid(w), w);
+        let period_y = h / gcd(delta.y.rem_euclid(h), h);
+        let period = period_x / gcd(period_x, period_y) * period_y;
+        // trees[k] is the number of trees in the first k steps of the cycle
+        let mut trees = vec![0u128];
+        for k in 0..period {
+            trees.push(trees[k as usize] + u128::from(tile_at(k)));
+        }
+        let (cycles, rest) = (steps / period as u128, steps % period as u128);
+        cycles * trees[period as usize] + trees[rest as usize]
+    }
+}
@@ -1,8 +1,9 @@
 use super::*;
+use aoc2020_common::solve;
 
 #[test]
 fn test_pair_from() {
-    let pair: Pair = (11, 22).into();
+    let pair: Vec2<i64> = (11, 22).into();
     assert_eq!(pair.x, 11);
     assert_eq!(pair.y, 22);
 }
@@ -41,3 +42,253 @@ fn test_index() {
     assert_eq!(m.index((0, 1).into()), Some(3));
     assert_eq!(m.index((2, 1).into()), Some(5));
 }
+
+#[test]
+fn test_debug_non_square() {
+    let m = RoadMap::parse("#..\n...\n").unwrap();
+    assert_eq!(m.size(), (3, 2).into());
+    assert_eq!(format!("{:?}", m), "#..\n...\n");
+}
+
+#[test]
+fn test_example() {
+    let [part1, part2] = solve::<Day3>(include_str!("../../fixtures/example.txt")).unwrap();
+    assert_eq!(part1.unwrap(), Answer::Number(7));
+    assert_eq!(part2.unwrap(), Answer::Number(336));
+}
+
+#[test]
+fn test_rank_slopes() {
+    let map = RoadMap::parse(include_str!("../../fixtures/example.txt")).unwrap();
+    let ranking = map.rank_slopes(1..=7, 0..=2);
+    assert_eq!(ranking.0.len(), 14, "dy = 0 is left out");
+    for &(delta, count) in &ranking.0 {
+        assert_eq!(count, count_trees(&map, delta));
+    }
+    assert!(ranking.0.windows(2).all(|w| w[0].1 <= w[1].1));
+    assert!(ranking.0.contains(&((3, 1).into(), 7)));
+
+    assert_eq!(ranking.fewest(), &[((5, 2).into(), 0)]);
+    assert_eq!(ranking.most(), &[((3, 1).into(), 7)]);
+
+    let ranking = map.rank_slopes(1..=1, 1..=2);
+    assert_eq!(ranking.0, vec![((1, 1).into(), 2), ((1, 2).into(), 2)]);
+    assert_eq!(ranking.fewest().len(), 2, "every tie is included");
+    assert_eq!(ranking.most().len(), 2, "every tie is included");
+}
+
+#[test]
+fn test_count_trees_many() {
+    let map = RoadMap::parse(include_str!("../../fixtures/example.txt")).unwrap();
+    let deltas: Vec<Vec2<i64>> = vec![
+        (1, 1).into(),
+        (3, 1).into(),
+        (5, 1).into(),
+        (7, 1).into(),
+        (1, 2).into(),
+        (-4, 3).into(),
+        (13, 1).into(),
+        (2, -1).into(),
+    ];
+    let expected: Vec<_> = deltas.iter().map(|&d| count_trees(&map, d)).collect();
+    assert_eq!(&expected[..5], &[2, 7, 3, 4, 2]);
+    assert_eq!(map.count_trees_many(&deltas), expected);
+
+    // slopes that never leave the map stop at the step limit
+    let flat: Vec<Vec2<i64>> = vec![(1, 0).into(), (3, 1).into(), (0, 0).into()];
+    let expected: Vec<_> = flat.iter().map(|&d| count_trees(&map, d)).collect();
+    assert_eq!(map.count_trees_many(&flat), expected);
+    let bounded = map.clone().with_boundary(aoc2020_common::Boundary::Bounded);
+    assert_eq!(bounded.count_trees_many(&[(1, 0).into()]), vec![2], "..##.......");
+
+    // the single pass stops at the step limit too
+    let deltas: Vec<Vec2<i64>> = vec![(3, 1).into(), (1, 2).into(), (2, -1).into()];
+    for limit in [0, 1, 3, 6] {
+        let limited = map.clone().with_step_limit(limit);
+        assert_eq!(limited.boundary(), aoc2020_common::Boundary::WrapX);
+        let expected: Vec<_> = deltas.iter().map(|&d| count_trees(&limited, d)).collect();
+        assert_eq!(limited.count_trees_many(&deltas), expected, "limit {}", limit);
+    }
+    assert_eq!(map.clone().with_step_limit(3).count_trees_many(&[(3, 1).into()]), vec![1]);
+
+    let packed = slopes::PackedMap::from(&map);
+    assert!(packed.is_tree((2, 0).into()));
+    assert!(packed.is_tree((13, 0).into()));
+    assert!(!packed.is_tree((0, 0).into()));
+    assert!(!packed.is_tree((2, 11).into()));
+}
+
+#[test]
+fn test_count_trees_wide_map() {
+    let row = |offset: usize| -> String {
+        (0..4000)
+            .map(|x| if (x + offset).is_multiple_of(7) { '#' } else { '.' })
+            .collect()
+    };
+    let input: Vec<_> = (0..2000).map(row).collect();
+    let map = RoadMap::parse(&input.join("\n")).unwrap();
+    let deltas: Vec<Vec2<i64>> = vec![(1, 1).into(), (6, 1).into(), (3, 4).into()];
+    let expected: Vec<_> = deltas.iter().map(|&d| count_trees(&map, d)).collect();
+    assert_eq!(map.count_trees_many(&deltas), expected);
+}
+
+#[test]
+fn test_render() {
+    use render::Style;
+
+    let map = RoadMap::parse(include_str!("../../fixtures/example.txt")).unwrap();
+    let rendered = map.render(&[generate_itinerary(&map, (3, 1).into())], Style::Plain);
+    let lines: Vec<_> = rendered.lines().collect();
+    assert_eq!(lines.len(), 11);
+    assert_eq!(lines[0], "O.##.........##.........##.......");
+    assert_eq!(lines[2], ".#....X..#..#....#..#..#....#..#.");
+    assert_eq!(lines[10], ".#..#...#.#.#..#...#.#.#..#...X.#");
+
+    assert_eq!(map.render(&[], Style::Plain), format!("{:?}", map));
+}
+
+#[test]
+fn test_render_non_square() {
+    use render::Style;
+
+    let map = RoadMap::parse("#..\n...\n").unwrap();
+    let paths = [vec![(0, 0).into(), (-1, 1).into()], vec![(4, 1).into(), (0, 5).into()]];
+    assert_eq!(
+        map.render(&paths, Style::Plain),
+        "#..X..#..\n..O....O.\n"
+    );
+    assert_eq!(
+        map.render(&[vec![(0, 0).into(), (1, 0).into()]], Style::Ansi),
+        "\x1b[1;31mX\x1b[0m\x1b[1;32mO\x1b[0m.\n...\n"
+    );
+}
+
+#[test]
+fn test_boundaries() {
+    use aoc2020_common::Boundary;
+    use render::Style;
+
+    let map = RoadMap::parse("#..\n.#.\n..#\n").unwrap();
+    let delta = Vec2::new(1, 1);
+    assert_eq!(map.boundary(), Boundary::WrapX);
+    assert_eq!(count_trees(&map, delta), 3);
+
+    let bounded = map.clone().with_boundary(Boundary::Bounded);
+    assert_eq!(generate_itinerary(&bounded, (2, 1).into()), vec![(0, 0).into(), (2, 1).into()]);
+    assert_eq!(bounded.count_trees_many(&[(2, 1).into(), (1, 1).into()]), vec![1, 3]);
+
+    // paths on these never end, so they stop at the step limit
+    let torus = map.clone().with_boundary(Boundary::Torus);
+    assert_eq!(torus.step_limit(), 36);
+    assert_eq!(count_trees(&torus, delta), 36);
+    let torus = torus.with_step_limit(7);
+    assert_eq!(generate_itinerary(&torus, delta).len(), 7);
+    assert_eq!(torus.count_trees_many(&[delta, (1, 0).into()]), vec![7, 3]);
+
+    let wrap_y = map.clone().with_boundary(Boundary::WrapY).with_step_limit(10);
+    assert_eq!(generate_itinerary(&wrap_y, (0, 1).into()).len(), 10);
+    assert_eq!(count_trees(&wrap_y, (1, 1).into()), 3, "leaves through the side");
+
+    let mirror = map.with_boundary(Boundary::Mirror).with_step_limit(6);
+    assert_eq!(
+        generate_itinerary(&mirror, (2, 1).into())
+            .into_iter()
+            .map(|pos| mirror.normalize_pos(pos).unwrap())
+            .collect::<Vec<_>>(),
+        vec![(0, 0).into(), (2, 1).into(), (1, 2).into(), (0, 2).into(), (2, 1).into(), (1, 0).into()]
+    );
+    assert_eq!(
+        mirror.render(&[generate_itinerary(&mirror, (2, 1).into())], Style::Plain),
+        "XO.\n.#O\nOO#\n"
+    );
+}
+
+#[test]
+fn test_cheapest_route() {
+    use aoc2020_common::Boundary;
+    use render::Style;
+    use route::{TileCosts, DOWNHILL};
+
+    let map = RoadMap::parse("##.\n.##\n#.#\n").unwrap();
+    let route = map.cheapest_route(&DOWNHILL, TileCosts::default()).unwrap();
+    assert_eq!(route.cost, 0, "wraps around the trees");
+    assert_eq!(route.path, vec![(2, 0).into(), (3, 1).into(), (4, 2).into()]);
+    assert_eq!(
+        map.render(&[route.path], Style::Plain),
+        "##O##.\n.##O##\n#.##O#\n"
+    );
+
+    let bounded = map.clone().with_boundary(Boundary::Bounded);
+    let route = bounded.cheapest_route(&DOWNHILL, TileCosts::default()).unwrap();
+    assert_eq!(route.cost, 1);
+    let costs = TileCosts { open: 1, tree: 10 };
+    assert_eq!(bounded.cheapest_route(&DOWNHILL, costs).unwrap().cost, 12);
+    assert_eq!(bounded.cheapest_route(&[(1, 0).into()], costs), None);
+
+    // diagonal moves can only help
+    let map = RoadMap::parse(include_str!("../../fixtures/example.txt")).unwrap();
+    let route = map.cheapest_route(&DOWNHILL, TileCosts::default()).unwrap();
+    let straight = map.cheapest_route(&[(0, 1).into()], TileCosts::default()).unwrap();
+    assert!(route.cost <= straight.cost);
+    assert_eq!(route.path.len(), 11);
+    assert!(route.path.windows(2).all(|w| DOWNHILL.contains(&(w[1] - w[0]))));
+    let hit = route.path.iter().filter(|&&pos| map.get(pos) == Tile::Tree).count();
+    assert_eq!(hit as u64, route.cost);
+}
+
+#[test]
+fn test_cheapest_route_reflected() {
+    use aoc2020_common::Boundary;
+    use route::TileCosts;
+
+    // every move off the right edge bounces straight back down column 2
+    let map = RoadMap::parse("##.\n##.\n##.\n").unwrap().with_boundary(Boundary::Mirror);
+    let route = map.cheapest_route(&[(1, 1).into()], TileCosts::default()).unwrap();
+    assert_eq!(route.cost, 0);
+    assert_eq!(route.path, vec![(2, 0).into(), (2, 1).into(), (2, 2).into()]);
+
+    let costs = TileCosts { open: 1, tree: 5 };
+    let moves: Vec<Vec2<i64>> = vec![(3, 1).into(), (-4, 1).into(), (1, -1).into()];
+    for boundary in [Boundary::Mirror, Boundary::Torus, Boundary::WrapY] {
+        let map = RoadMap::parse(include_str!("../../fixtures/example.txt"))
+            .unwrap()
+            .with_boundary(boundary);
+        let route = map.cheapest_route(&moves, costs).unwrap();
+        assert!(route.path.iter().all(|&pos| map.normalize_pos(pos) == Some(pos)));
+        let total: u64 = route.path.iter().map(|&pos| costs.cost(map.get(pos))).sum();
+        assert_eq!(total, route.cost, "{:?}", boundary);
+    }
+}
+
+#[test]
+fn test_count_trees_stacked() {
+    let map = RoadMap::parse(include_str!("../../fixtures/example.txt")).unwrap();
+    let deltas: Vec<Vec2<i64>> = vec![
+        (3, 1).into(),
+        (1, 2).into(),
+        (0, 1).into(),
+        (-4, 3).into(),
+        (13, 1).into(),
+        (2, 7).into(),
+        (5, 25).into(),
+        (2, -1).into(),
+    ];
+    for copies in 0..6 {
+        let stacked = map.stack(copies);
+        assert_eq!(stacked.size(), Vec2::new(11, 11 * copies as i64));
+        for &delta in &deltas {
+            assert_eq!(
+                map.count_trees_stacked(delta, copies as u64),
+                count_trees(&stacked, delta) as u128,
+                "{:?} on {} copies",
+                delta,
+                copies
+            );
+        }
+    }
+
+    // 3,1 comes back to the top left corner after every copy
+    assert_eq!(map.count_trees_stacked((3, 1).into(), 10u64.pow(18)), 7 * 10u128.pow(18));
+    let forest = RoadMap::parse("##\n##\n##\n").unwrap();
+    assert_eq!(forest.count_trees_stacked((1, 2).into(), u64::MAX), u128::from(u64::MAX) * 3 / 2 + 1);
+}
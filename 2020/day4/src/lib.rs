@@ -0,0 +1,237 @@
+use aoc2020_common::{Answer, Solution};
+
+pub mod problems;
+pub use problems::Problem;
+
+#[cfg(test)]
+mod test;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Parsed<'a> = Vec<Result<PassportBuilder<'a>, Error>>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed<'_>> {
+        Ok(input.split("\n\n").map(PassportBuilder::parse).collect())
+    }
+
+    fn part1(records: &Self::Parsed<'_>) -> anyhow::Result<Answer> {
+        Ok(count_valid(records, Validation::Presence).into())
+    }
+
+    fn part2(records: &Self::Parsed<'_>) -> anyhow::Result<Answer> {
+        Ok(count_valid(records, Validation::Strict).into())
+    }
+}
+
+/// How much of a passport is checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Validation {
+    /// Every required field is there, whatever its value (part 1).
+    Presence,
+    /// Every required field is there and holds a valid value (part 2).
+    #[default]
+    Strict,
+}
+
+/// The number of records that parsed and pass validation at `level`.
+pub fn count_valid(records: &[Result<PassportBuilder<'_>, Error>], level: Validation) -> usize {
+    records
+        .iter()
+        .filter(|r| matches!(r, Ok(b) if b.validate(level).is_ok()))
+        .count()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Year(pub u64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Length {
+    Cm(u64),
+    In(u64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color<'a>(pub &'a str);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ID<'a>(pub &'a str);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Passport<'a> {
+    pub birth_year: Year,
+    pub issue_year: Year,
+    pub expiration_year: Year,
+    pub height: Length,
+    pub hair_color: Color<'a>,
+    pub eye_color: Color<'a>,
+    pub passport_id: ID<'a>,
+    pub country_id: Option<ID<'a>>,
+}
+
+/// The fields of one record as written, none of them checked yet.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct PassportBuilder<'a> {
+    pub birth_year: Option<&'a str>,
+    pub issue_year: Option<&'a str>,
+    pub expiration_year: Option<&'a str>,
+    pub height: Option<&'a str>,
+    pub hair_color: Option<&'a str>,
+    pub eye_color: Option<&'a str>,
+    pub passport_id: Option<&'a str>,
+    pub country_id: Option<&'a str>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("invalid passport: {}", join(.0))]
+    Invalid(Vec<Problem>),
+
+    #[error("could not parse {0}: {1}")]
+    ParseError(String, String),
+}
+
+fn join(problems: &[Problem]) -> String {
+    problems.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+}
+
+/// The fields every passport needs; `cid` is optional.
+pub const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+impl<'a> PassportBuilder<'a> {
+    /// The value of the field with the given key, such as `"byr"`.
+    pub fn get(&self, key: &str) -> Option<&'a str> {
+        match key {
+            "byr" => self.birth_year,
+            "iyr" => self.issue_year,
+            "eyr" => self.expiration_year,
+            "hgt" => self.height,
+            "hcl" => self.hair_color,
+            "ecl" => self.eye_color,
+            "pid" => self.passport_id,
+            "cid" => self.country_id,
+            _ => None,
+        }
+    }
+
+    fn set(&mut self, key: &str, value: &'a str) -> Result<(), &'static str> {
+        let field = match key {
+            "byr" => &mut self.birth_year,
+            "iyr" => &mut self.issue_year,
+            "eyr" => &mut self.expiration_year,
+            "hgt" => &mut self.height,
+            "hcl" => &mut self.hair_color,
+            "ecl" => &mut self.eye_color,
+            "pid" => &mut self.passport_id,
+            "cid" => &mut self.country_id,
+            _ => return Err("unknown field"),
+        };
+        *field = Some(value);
+        Ok(())
+    }
+
+    /// Everything wrong with the record at the given level, in the order of
+    /// `REQUIRED`, or nothing if it is valid.
+    pub fn problems(&self, level: Validation) -> Vec<Problem> {
+        match level {
+            Validation::Presence => REQUIRED
+                .into_iter()
+                .filter(|key| self.get(key).is_none())
+                .map(Problem::Missing)
+                .collect(),
+            Validation::Strict => match self.build() {
+                Err(Error::Invalid(problems)) => problems,
+                _ => Vec::new(),
+            },
+        }
+    }
+
+    /// Checks the record at the given level, with every problem it has.
+    pub fn validate(&self, level: Validation) -> Result<(), Error> {
+        match self.problems(level) {
+            problems if problems.is_empty() => Ok(()),
+            problems => Err(Error::Invalid(problems)),
+        }
+    }
+
+    /// Checks every field strictly and converts them, collecting every
+    /// problem rather than stopping at the first.
+    pub fn build(self) -> Result<Passport<'a>, Error> {
+        fn check<'a, T>(
+            found: &mut Vec<Problem>,
+            key: &'static str,
+            value: Option<&'a str>,
+            parse: impl FnOnce(&'static str, &'a str) -> Result<T, Problem>,
+        ) -> Option<T> {
+            let problem = match value.map(|v| parse(key, v)) {
+                Some(Ok(value)) => return Some(value),
+                Some(Err(problem)) => problem,
+                None => Problem::Missing(key),
+            };
+            found.push(problem);
+            None
+        }
+
+        let mut found = Vec::new();
+        let birth_year = check(&mut found, "byr", self.birth_year, |k, v| {
+            problems::year(k, v, 1920..=2002)
+        });
+        let issue_year = check(&mut found, "iyr", self.issue_year, |k, v| {
+            problems::year(k, v, 2010..=2020)
+        });
+        let expiration_year = check(&mut found, "eyr", self.expiration_year, |k, v| {
+            problems::year(k, v, 2020..=2030)
+        });
+        let height = check(&mut found, "hgt", self.height, problems::height);
+        let hair_color = check(&mut found, "hcl", self.hair_color, problems::hair_color);
+        let eye_color = check(&mut found, "ecl", self.eye_color, problems::eye_color);
+        let passport_id = check(&mut found, "pid", self.passport_id, problems::passport_id);
+
+        let (
+            Some(birth_year),
+            Some(issue_year),
+            Some(expiration_year),
+            Some(height),
+            Some(hair_color),
+            Some(eye_color),
+            Some(passport_id),
+        ) = (birth_year, issue_year, expiration_year, height, hair_color, eye_color, passport_id)
+        else {
+            return Err(Error::Invalid(found));
+        };
+        Ok(Passport {
+            birth_year,
+            issue_year,
+            expiration_year,
+            height,
+            hair_color,
+            eye_color,
+            passport_id,
+            country_id: self.country_id.map(ID),
+        })
+    }
+
+    /// Splits a record into its fields, without checking their values.
+    ///
+    /// Fails on unknown keys and on anything that is not `key:value`.
+    pub fn parse(input: &'a str) -> Result<Self,Error> {
+        let mut b: Self = Default::default();
+
+        peg::parser! {
+            grammar parser() for str {
+                pub(crate) rule root(b: &mut PassportBuilder<'input>)
+                    = separator()* (field(b) separator()*)* ![_]
+
+                rule separator()
+                    = ['\n' | ' ']
+
+                rule field(b: &mut PassportBuilder<'input>)
+                    = key:$(['a'..='z']+) ":" value:$((!separator()[_])+) {? b.set(key, value) }
+            }
+        }
+
+        parser::root(input, &mut b).map_err(|e| Error::ParseError(input.into(), e.to_string()))?;
+        Ok(b)
+    }
+}
@@ -0,0 +1,151 @@
+//! Everything wrong with a passport's fields, so that a record can be fixed
+//! in one go.
+
+use crate::{Color, Length, Year, ID};
+use std::ops::RangeInclusive;
+
+const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+/// One thing wrong with one field.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    #[error("{0} missing")]
+    Missing(&'static str),
+
+    #[error("{field} {value:?} is not a number")]
+    NotANumber { field: &'static str, value: String },
+
+    #[error("{field} has {found} digits")]
+    Digits {
+        field: &'static str,
+        found: usize,
+        expected: usize,
+    },
+
+    #[error("{field} {value} below {min}")]
+    Below {
+        field: &'static str,
+        value: u64,
+        min: u64,
+    },
+
+    #[error("{field} {value} above {max}")]
+    Above {
+        field: &'static str,
+        value: u64,
+        max: u64,
+    },
+
+    #[error("{0} missing unit")]
+    MissingUnit(&'static str),
+
+    #[error("{field} {value:?} is not a colour such as #a1b2c3")]
+    NotAHexColor { field: &'static str, value: String },
+
+    #[error("{field} {value:?} is not one of {}", EYE_COLORS.join(", "))]
+    UnknownEyeColor { field: &'static str, value: String },
+}
+
+impl Problem {
+    /// The key of the field at fault, such as `"byr"`.
+    pub fn field(&self) -> &'static str {
+        match self {
+            Self::Missing(field) | Self::MissingUnit(field) => field,
+            Self::NotANumber { field, .. }
+            | Self::Digits { field, .. }
+            | Self::Below { field, .. }
+            | Self::Above { field, .. }
+            | Self::NotAHexColor { field, .. }
+            | Self::UnknownEyeColor { field, .. } => field,
+        }
+    }
+}
+
+/// Checks that `value` is all digits, without limiting how many.
+fn digits<'a>(field: &'static str, value: &'a str) -> Result<&'a str, Problem> {
+    if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+        Ok(value)
+    } else {
+        Err(Problem::NotANumber {
+            field,
+            value: value.into(),
+        })
+    }
+}
+
+fn number_in(field: &'static str, value: &str, range: RangeInclusive<u64>) -> Result<u64, Problem> {
+    let value = digits(field, value)?;
+    // too many digits to parse is also too many for the range
+    let value = value.parse().unwrap_or(u64::MAX);
+    if value < *range.start() {
+        Err(Problem::Below {
+            field,
+            value,
+            min: *range.start(),
+        })
+    } else if value > *range.end() {
+        Err(Problem::Above {
+            field,
+            value,
+            max: *range.end(),
+        })
+    } else {
+        Ok(value)
+    }
+}
+
+fn digit_count(field: &'static str, value: &str, expected: usize) -> Result<(), Problem> {
+    match digits(field, value)?.len() {
+        found if found == expected => Ok(()),
+        found => Err(Problem::Digits {
+            field,
+            found,
+            expected,
+        }),
+    }
+}
+
+pub(crate) fn year(
+    field: &'static str,
+    value: &str,
+    range: RangeInclusive<u64>,
+) -> Result<Year, Problem> {
+    digit_count(field, value, 4)?;
+    number_in(field, value, range).map(Year)
+}
+
+pub(crate) fn height(field: &'static str, value: &str) -> Result<Length, Problem> {
+    if let Some(cm) = value.strip_suffix("cm") {
+        number_in(field, cm, 150..=193).map(Length::Cm)
+    } else if let Some(inches) = value.strip_suffix("in") {
+        number_in(field, inches, 59..=76).map(Length::In)
+    } else {
+        Err(Problem::MissingUnit(field))
+    }
+}
+
+pub(crate) fn hair_color<'a>(field: &'static str, value: &'a str) -> Result<Color<'a>, Problem> {
+    let lower_hex = |b: u8| matches!(b, b'0'..=b'9' | b'a'..=b'f');
+    match value.strip_prefix('#') {
+        Some(hex) if hex.len() == 6 && hex.bytes().all(lower_hex) => Ok(Color(value)),
+        _ => Err(Problem::NotAHexColor {
+            field,
+            value: value.into(),
+        }),
+    }
+}
+
+pub(crate) fn eye_color<'a>(field: &'static str, value: &'a str) -> Result<Color<'a>, Problem> {
+    if EYE_COLORS.contains(&value) {
+        Ok(Color(value))
+    } else {
+        Err(Problem::UnknownEyeColor {
+            field,
+            value: value.into(),
+        })
+    }
+}
+
+pub(crate) fn passport_id<'a>(field: &'static str, value: &'a str) -> Result<ID<'a>, Problem> {
+    digit_count(field, value, 9).map(|_| ID(value))
+}
@@ -0,0 +1,93 @@
+use super::*;
+use aoc2020_common::solve;
+
+#[test]
+fn test_example_part1() {
+    let [part1, _] = solve::<Day4>(include_str!("../../fixtures/example.txt")).unwrap();
+    assert_eq!(part1.unwrap(), Answer::Number(2));
+}
+
+#[test]
+fn test_example_part2() {
+    let [_, part2] = solve::<Day4>(include_str!("../../fixtures/example.txt")).unwrap();
+    assert_eq!(part2.unwrap(), Answer::Number(2));
+    let [_, part2] = solve::<Day4>(include_str!("../../fixtures/invalid.txt")).unwrap();
+    assert_eq!(part2.unwrap(), Answer::Number(0));
+    let [_, part2] = solve::<Day4>(include_str!("../../fixtures/valid.txt")).unwrap();
+    assert_eq!(part2.unwrap(), Answer::Number(4));
+}
+
+#[test]
+fn test_validation_levels() {
+    let records = Day4::parse(include_str!("../../fixtures/invalid.txt")).unwrap();
+    assert_eq!(count_valid(&records, Validation::Presence), 4);
+    assert_eq!(count_valid(&records, Validation::Strict), 0);
+
+    let b = PassportBuilder::parse(
+        "byr:1900 iyr:2015 eyr:2025 hgt:60in\nhcl:#123abc ecl:blu pid:000000001\n",
+    )
+    .unwrap();
+    assert!(b.validate(Validation::Presence).is_ok());
+    assert_eq!(
+        b.problems(Validation::Strict),
+        vec![Problem::Below {
+            field: "byr",
+            value: 1900,
+            min: 1920
+        }]
+    );
+    let b = PassportBuilder { eye_color: None, ..b };
+    assert_eq!(b.problems(Validation::Presence), vec![Problem::Missing("ecl")]);
+    assert!(matches!(b.validate(Validation::Presence), Err(Error::Invalid(_))));
+    assert!(matches!(
+        PassportBuilder::parse("byr:1937 xyz:1"),
+        Err(Error::ParseError(..))
+    ));
+}
+
+#[test]
+fn test_problems() {
+    let describe = |record: &str| -> Vec<String> {
+        PassportBuilder::parse(record)
+            .unwrap()
+            .problems(Validation::Strict)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    };
+    assert_eq!(
+        describe("byr:1900 iyr:2021 eyr:20300 hgt:170 hcl:123abc pid:12345678"),
+        vec![
+            "byr 1900 below 1920",
+            "iyr 2021 above 2020",
+            "eyr has 5 digits",
+            "hgt missing unit",
+            "hcl \"123abc\" is not a colour such as #a1b2c3",
+            "ecl missing",
+            "pid has 8 digits",
+        ]
+    );
+    assert_eq!(
+        describe("byr:19x0 iyr:2010 eyr:2030 hgt:77in hcl:#abcdef ecl:hzl pid:0123456789"),
+        vec!["byr \"19x0\" is not a number", "hgt 77 above 76", "pid has 10 digits"]
+    );
+    assert_eq!(
+        describe("byr:2002 iyr:2010 eyr:2030 hgt:149cm hcl:#abcdef ecl:red pid:012345678"),
+        vec![
+            "hgt 149 below 150",
+            "ecl \"red\" is not one of amb, blu, brn, gry, grn, hzl, oth",
+        ]
+    );
+
+    for record in include_str!("../../fixtures/valid.txt").split("\n\n") {
+        assert_eq!(describe(record), Vec::<String>::new());
+    }
+    let b = PassportBuilder::parse("hgt:59cm ecl:zzz").unwrap();
+    let problems = b.problems(Validation::Strict);
+    assert_eq!(problems.len(), 7);
+    assert_eq!(
+        problems.iter().map(Problem::field).collect::<Vec<_>>(),
+        REQUIRED,
+        "in the order of REQUIRED"
+    );
+}
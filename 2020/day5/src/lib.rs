@@ -0,0 +1,80 @@
+use aoc2020_common::{Answer, Solution};
+use anyhow::{anyhow, bail, Context, Result};
+use bitvec::prelude::*;
+
+#[cfg(test)]
+mod test;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Parsed<'a> = Vec<Seat>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let mut seats = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Seat::parse(line).with_context(|| format!("line {}", i + 1)))
+            .collect::<Result<Vec<_>>>()?;
+        seats.sort();
+        Ok(seats)
+    }
+
+    fn part1(seats: &Vec<Seat>) -> Result<Answer> {
+        let max_id = seats
+            .iter()
+            .map(Seat::id)
+            .max()
+            .ok_or_else(|| anyhow!("no boarding passes in the input"))?;
+        Ok(max_id.into())
+    }
+
+    fn part2(seats: &Vec<Seat>) -> Result<Answer> {
+        let mut last_id: Option<Seat> = None;
+        for &id in seats {
+            if let Some(last_id) = last_id {
+                let gap = id.0 - last_id.0;
+                if gap > 1 {
+                    return Ok((last_id.0 + 1).into());
+                }
+            }
+            last_id = Some(id);
+        }
+        bail!("no gap between the seat IDs")
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Seat(pub u16);
+
+impl Seat {
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut res: Seat = Default::default();
+
+        let bits = BitSlice::<_, Lsb0>::from_element_mut(&mut res.0);
+        if input.is_empty() || input.len() > bits.len() {
+            bail!(
+                "a boarding pass has 1 to {} letters, not {}",
+                bits.len(),
+                input.len()
+            );
+        }
+        for (i, c) in input.chars().rev().enumerate() {
+            bits.set(
+                i,
+                match c {
+                    'F' | 'L' => false,
+                    'B' | 'R' => true,
+                    _ => bail!("unexpected letter: {:?}", c),
+                },
+            )
+        }
+
+        Ok(res)
+    }
+
+    pub fn id(&self) -> u64 {
+        self.0 as u64
+    }
+}
@@ -1,8 +1,30 @@
 use super::*;
+use aoc2020_common::solve;
 
 #[test]
 fn test_parse() {
-    assert_eq!(Seat::parse("BFFFBBFRRR"), Seat(567));
-    assert_eq!(Seat::parse("FFFBBBFRRR"), Seat(119));
-    assert_eq!(Seat::parse("BBFFBBFRLL"), Seat(820));
-}
\ No newline at end of file
+    assert_eq!(Seat::parse("BFFFBBFRRR").unwrap(), Seat(567));
+    assert_eq!(Seat::parse("FFFBBBFRRR").unwrap(), Seat(119));
+    assert_eq!(Seat::parse("BBFFBBFRLL").unwrap(), Seat(820));
+}
+
+#[test]
+fn test_example() {
+    let [part1, _] = solve::<Day5>(include_str!("../../fixtures/example.txt")).unwrap();
+    assert_eq!(part1.unwrap(), Answer::Number(820));
+}
+
+#[test]
+fn test_missing_seat() {
+    let seats = Day5::parse("FFFFFFFLLR\nFFFFFFFRLL\nFFFFFFFLRL\nFFFFFFFRRL").unwrap();
+    assert_eq!(Day5::part2(&seats).unwrap(), Answer::Number(3));
+}
+
+#[test]
+fn test_malformed() {
+    for input in ["BFFFBBFRRR\n\nFFFBBBFRRR", "BFFFBXFRRR", "BFFFBBFRRRBFFFBBFRRR", "BFFFBBFRRé"] {
+        assert!(Day5::parse(input).is_err(), "{:?}", input);
+    }
+    let error = Day5::parse("BFFFBBFRRR\nBFFFBXFRRR").unwrap_err();
+    assert_eq!(format!("{:#}", error), "line 2: unexpected letter: 'X'");
+}
@@ -0,0 +1,65 @@
+use aoc2020_common::{Answer, Solution};
+use anyhow::Result;
+use im::HashSet;
+use std::fmt;
+
+#[cfg(test)]
+mod test;
+
+pub struct Answers(pub HashSet<u8>);
+
+impl fmt::Debug for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for &answer in &self.0 {
+            write!(f, "{}", answer as char)?;
+        }
+        Ok(())
+    }
+}
+
+/// The answers of every person in each group.
+pub type Groups = Vec<Vec<Answers>>;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Parsed<'a> = Groups;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input
+            .split("\n\n")
+            .map(|group| {
+                group
+                    .lines()
+                    .map(|line| Answers(line.as_bytes().iter().copied().collect()))
+                    .collect()
+            })
+            .collect())
+    }
+
+    fn part1(groups: &Groups) -> Result<Answer> {
+        // Questions to which at least one person per group answered yes
+        let answer: usize = groups
+            .iter()
+            .map(|group| HashSet::<u8>::unions(group.iter().map(|a| a.0.clone())).len())
+            .sum();
+        Ok(answer.into())
+    }
+
+    fn part2(groups: &Groups) -> Result<Answer> {
+        // Questions to which everyone in a group answered yes
+        let answer: usize = groups
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .map(|a| a.0.clone())
+                    .reduce(|acc, x| acc.intersection(x))
+                    .unwrap_or_default()
+                    .len()
+            })
+            .sum();
+        Ok(answer.into())
+    }
+}
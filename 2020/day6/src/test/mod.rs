@@ -0,0 +1,9 @@
+use super::*;
+use aoc2020_common::solve;
+
+#[test]
+fn test_example() {
+    let [part1, part2] = solve::<Day6>(include_str!("../../fixtures/example.txt")).unwrap();
+    assert_eq!(part1.unwrap(), Answer::Number(11));
+    assert_eq!(part2.unwrap(), Answer::Number(6));
+}
@@ -0,0 +1,148 @@
+use aoc2020_common::{Answer, Solution};
+use anyhow::Result;
+use multimap::MultiMap;
+use std::fmt;
+use itertools::Itertools;
+
+#[cfg(test)]
+mod test;
+
+pub type BagSpec<'a> = (&'a str, &'a str);
+pub type Rules<'a> = MultiMap<BagSpec<'a>, (usize, BagSpec<'a>)>;
+
+pub fn parse_rules(input: &str) -> Result<Rules<'_>> {
+    let mut rules: Rules = Default::default();
+    peg::parser! {
+        pub(crate) grammar parser() for str {
+            pub(crate) rule root(r: &mut Rules<'input>)
+            = (line(r) "." whitespace()*)* ![_]
+
+            rule line(r: &mut Rules<'input>)
+            = spec:bag_spec() " contain " rules:rules() {
+                if let Some(rules) = rules {
+                    for rule in rules {
+                        r.insert(spec, rule)
+                    }
+                }
+            }
+
+            rule bag_spec() -> BagSpec<'input>
+            = adjective:name() " " color:name() " bag" "s"? { (adjective, color) }
+
+            rule rules() -> Option<Vec<(usize, BagSpec<'input>)>>
+            = rules:rule1()+ { Some(rules) }
+            / "no other bags" { None }
+
+            rule rule1() -> (usize, BagSpec<'input>)
+            = r:rule0() ", "? { r }
+
+            rule rule0() -> (usize, BagSpec<'input>)
+            = count:num() " " spec:bag_spec() { (count,spec) }
+
+            rule num() -> usize
+            = n:$(['0'..='9']+) {? n.parse().or(Err("number")) }
+
+            rule name() -> &'input str
+            = s:$((!whitespace()[_])*)
+
+            rule whitespace()
+            = [ '\t' | '\r' | '\n' | ' ' ]
+        }
+    }
+    parser::root(input, &mut rules)?;
+    Ok(rules)
+}
+
+pub struct FormattedRules<'a>(pub Rules<'a>);
+
+impl fmt::Display for FormattedRules<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (k, vv) in &self.0 {
+            write!(f, "{} {} bags can contain ", k.0, k.1)?;
+            if vv.is_empty() {
+                write!(f, "no other bags")?;
+            } else {
+                for (i, v) in vv.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(
+                        f,
+                        "{} {} {} {}",
+                        v.0,
+                        v.1 .0,
+                        v.1 .1,
+                        if v.0 == 1 { "bag" } else { "bags" }
+                    )?;
+                }
+            }
+            writeln!(f, ".")?;
+        }
+        Ok(())
+    }
+}
+
+pub fn reverse_graph<'a>(graph: &Rules<'a>) -> Rules<'a> {
+    graph
+        .iter_all()
+        .flat_map(|(&node, neighbors)| {
+            neighbors
+                .iter()
+                .map(move |&(count, neighbor)| (neighbor, (count, node)))
+        })
+        .collect()
+}
+
+pub fn walk_subgraph<'iter, 'elems: 'iter>(
+    graph: &'iter Rules<'elems>,
+    root: &(&'iter str, &'iter str),
+) -> Box<dyn Iterator<Item = (&'elems str, &'elems str)> + 'iter> {
+    Box::new(
+        graph
+        .get_vec(root)
+        .into_iter()
+        .flatten()
+        .flat_map(move |&(_,neighbor)| {
+            std::iter::once(neighbor).chain(walk_subgraph(graph, &neighbor))
+        })
+    )
+}
+
+pub fn bag_quantities<'iter, 'elems: 'iter>(
+    graph: &'iter Rules<'elems>,
+    root: &(&'iter str,&'iter str),
+) -> Box<dyn Iterator<Item = usize> + 'iter> {
+    Box::new(
+        graph
+        .get_vec(root)
+        .into_iter()
+        .flatten()
+        .flat_map(move |&(count, neighbor)| {
+            std::iter::once(count).chain(bag_quantities(graph, &neighbor).map(move |x| x * count))
+        })
+    )
+}
+
+pub const TARGET_BAG: BagSpec<'static> = ("shiny", "gold");
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Parsed<'a> = Rules<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse_rules(input)
+    }
+
+    fn part1(rules: &Rules<'_>) -> Result<Answer> {
+        // colors that can eventually contain the target bag
+        let rev_rules = reverse_graph(rules);
+        Ok(walk_subgraph(&rev_rules, &TARGET_BAG).unique().count().into())
+    }
+
+    fn part2(rules: &Rules<'_>) -> Result<Answer> {
+        // bags needed inside the target bag
+        Ok(bag_quantities(rules, &TARGET_BAG).sum::<usize>().into())
+    }
+}
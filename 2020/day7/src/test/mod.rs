@@ -0,0 +1,31 @@
+use super::*;
+use aoc2020_common::solve;
+
+#[test]
+fn test_example() {
+    let [part1, part2] = solve::<Day7>(include_str!("../../fixtures/example.txt")).unwrap();
+    assert_eq!(part1.unwrap(), Answer::Number(4));
+    assert_eq!(part2.unwrap(), Answer::Number(32));
+}
+
+#[test]
+fn test_example2() {
+    let [_, part2] = solve::<Day7>(include_str!("../../fixtures/example2.txt")).unwrap();
+    assert_eq!(part2.unwrap(), Answer::Number(126));
+}
+
+#[test]
+fn test_reverse_graph() {
+    let rules = parse_rules(include_str!("../../fixtures/example.txt")).unwrap();
+    let rev_rules = reverse_graph(&rules);
+    let mut parents = rev_rules.get_vec(&("shiny", "gold")).unwrap().clone();
+    parents.sort();
+    assert_eq!(parents, vec![(1, ("bright", "white")), (2, ("muted", "yellow"))]);
+}
+
+#[test]
+fn test_malformed() {
+    let too_many = "light red bags contain 99999999999999999999999 bright white bags.\n";
+    assert!(Day7::parse(too_many).is_err());
+    assert!(Day7::parse("light red bags contain some bright white bags.\n").is_err());
+}
@@ -0,0 +1,146 @@
+use aoc2020_common::{Answer, Solution};
+use anyhow::{anyhow, bail, Context, Result};
+use std::collections::HashSet;
+
+#[cfg(test)]
+mod test;
+
+#[derive(Debug, Clone, Copy)]
+pub enum InstructionKind {
+    Nop,
+    Acc,
+    Jmp,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    pub kind: InstructionKind,
+    pub operand: isize,
+}
+
+pub type Program = Vec<Instruction>;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    pub pc: usize,
+    pub acc: isize,
+}
+
+impl State {
+    pub fn next(self, program: &Program) -> Option<Self> {
+        if !(0..program.len()).contains(&self.pc) {
+            None
+        } else {
+            let ins = program[self.pc];
+            Some(match ins.kind {
+                InstructionKind::Nop => Self {
+                    pc: self.pc + 1,
+                    ..self
+                },
+                InstructionKind::Acc => Self {
+                    pc: self.pc + 1,
+                    acc: self.acc + ins.operand
+                },
+                InstructionKind::Jmp => Self {
+                    pc: (self.pc as isize + ins.operand).try_into().unwrap(),
+                    ..self
+                }
+            })            
+        }
+    }
+}
+
+pub fn parse_program(input: &str) -> Result<Program> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            let (kind, operand) = l
+                .split_once(' ')
+                .ok_or_else(|| anyhow!("line {}: expected an instruction and an operand", i + 1))?;
+            Ok(Instruction {
+                kind: match kind {
+                    "nop" => InstructionKind::Nop,
+                    "acc" => InstructionKind::Acc,
+                    "jmp" => InstructionKind::Jmp,
+                    _ => bail!("line {}: unknown instruction kind {:?}", i + 1, kind),
+                },
+                operand: operand
+                    .parse()
+                    .with_context(|| format!("line {}: invalid operand {:?}", i + 1, operand))?,
+            })
+        })
+        .collect()
+}
+
+/// How a program run ended.
+#[derive(Debug, Clone, Copy)]
+pub enum Outcome {
+    /// The instruction at `pc` was about to run a second time.
+    Looped(State),
+    /// The program counter moved just past the last instruction.
+    Terminated(State),
+}
+
+pub fn run(program: &Program) -> Outcome {
+    let mut visited: HashSet<usize> = Default::default();
+    let mut state = State::default();
+    loop {
+        if !visited.insert(state.pc) {
+            return Outcome::Looped(state);
+        }
+        match state.next(program) {
+            Some(next) => state = next,
+            None => return Outcome::Terminated(state),
+        }
+    }
+}
+
+pub fn flip_kind(kind: &mut InstructionKind) {
+    *kind = match *kind {
+        InstructionKind::Jmp => InstructionKind::Nop,
+        InstructionKind::Nop => InstructionKind::Jmp,
+        x => x,
+    };
+}
+
+/// Flips each `jmp`/`nop` in turn until the program terminates.
+pub fn find_variant(program: &Program) -> Option<State> {
+    program
+        .iter()
+        .enumerate()
+        .filter(|(_, ins)| matches!(ins.kind, InstructionKind::Jmp | InstructionKind::Nop))
+        .find_map(|(index, _)| {
+            let mut variant = program.clone();
+            flip_kind(&mut variant[index].kind);
+            match run(&variant) {
+                Outcome::Terminated(state) => Some(state),
+                Outcome::Looped(_) => None,
+            }
+        })
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Parsed<'a> = Program;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse_program(input)
+    }
+
+    fn part1(program: &Program) -> Result<Answer> {
+        // accumulator right before an instruction runs a second time
+        match run(program) {
+            Outcome::Looped(state) => Ok(state.acc.into()),
+            Outcome::Terminated(_) => bail!("the program terminated without looping"),
+        }
+    }
+
+    fn part2(program: &Program) -> Result<Answer> {
+        let state = find_variant(program)
+            .ok_or_else(|| anyhow!("no single jmp/nop flip makes the program terminate"))?;
+        Ok(state.acc.into())
+    }
+}
@@ -0,0 +1,28 @@
+use super::*;
+use aoc2020_common::solve;
+
+const EXAMPLE: &str = include_str!("../../fixtures/example.txt");
+
+#[test]
+fn test_example() {
+    let [part1, part2] = solve::<Day8>(EXAMPLE).unwrap();
+    assert_eq!(part1.unwrap(), Answer::Number(5));
+    assert_eq!(part2.unwrap(), Answer::Number(8));
+}
+
+#[test]
+fn test_run() {
+    let mut program = parse_program(EXAMPLE).unwrap();
+    assert!(matches!(run(&program), Outcome::Looped(State { pc: 1, acc: 5 })));
+    flip_kind(&mut program[7].kind);
+    assert!(matches!(run(&program), Outcome::Terminated(State { pc: 9, acc: 8 })));
+}
+
+#[test]
+fn test_malformed() {
+    for input in ["nop +0\n\nacc +1", "nop +0\nmul +2", "acc", "jmp +x"] {
+        assert!(Day8::parse(input).is_err(), "{:?}", input);
+    }
+    let error = Day8::parse("nop +0\nmul +2").unwrap_err();
+    assert_eq!(error.to_string(), "line 2: unknown instruction kind \"mul\"");
+}
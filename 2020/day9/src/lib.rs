@@ -0,0 +1,68 @@
+use aoc2020_common::{Answer, Solution};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+#[cfg(test)]
+mod test;
+
+/// How many previous numbers each number is checked against.
+pub const PREAMBLE: usize = 25;
+
+/// Finds the first number that is not the sum of two of the `preamble`
+/// numbers before it.
+pub fn first_weakness(numbers: &[usize], preamble: usize) -> Option<usize> {
+    numbers.windows(preamble + 1).find_map(|s| {
+        if s[..preamble]
+            .iter()
+            .tuple_combinations()
+            .any(|(x, y)| x + y == s[preamble])
+        {
+            None
+        } else {
+            Some(s[preamble])
+        }
+    })
+}
+
+/// Finds a contiguous run of at least two numbers summing to `weakness`, and
+/// adds its smallest and largest numbers together.
+pub fn final_weakness(numbers: &[usize], weakness: usize) -> Option<usize> {
+    let (start, size, _) = (2..numbers.len())
+        .flat_map(|n| {
+            numbers
+                .windows(n)
+                .enumerate()
+                .map(move |(i, window)| (i, n, window.iter().sum::<usize>()))
+        })
+        .find(|&(_, _, sum)| sum == weakness)?;
+    let data_set = &numbers[start..(start + size)];
+    Some(data_set.iter().min()? + data_set.iter().max()?)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Parsed<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input
+            .lines()
+            .map(str::parse)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    fn part1(numbers: &Vec<usize>) -> Result<Answer> {
+        let weakness = first_weakness(numbers, PREAMBLE)
+            .ok_or_else(|| anyhow!("every number is a sum of two previous numbers"))?;
+        Ok(weakness.into())
+    }
+
+    fn part2(numbers: &Vec<usize>) -> Result<Answer> {
+        let weakness = first_weakness(numbers, PREAMBLE)
+            .ok_or_else(|| anyhow!("every number is a sum of two previous numbers"))?;
+        let answer = final_weakness(numbers, weakness)
+            .ok_or_else(|| anyhow!("no contiguous set sums to {}", weakness))?;
+        Ok(answer.into())
+    }
+}
@@ -0,0 +1,9 @@
+use super::*;
+
+#[test]
+fn test_example() {
+    // the example checks each number against the previous 5 instead of 25
+    let numbers = Day9::parse(include_str!("../../fixtures/example.txt")).unwrap();
+    assert_eq!(first_weakness(&numbers, 5), Some(127));
+    assert_eq!(final_weakness(&numbers, 127), Some(62));
+}